@@ -0,0 +1,87 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface BatchOrderFields {
+  lamports: BN
+}
+
+export interface BatchOrderJSON {
+  lamports: string
+}
+
+export class BatchOrder {
+  readonly lamports: BN
+
+  static readonly discriminator = Buffer.from([
+    62, 98, 197, 149, 100, 85, 144, 130,
+  ])
+
+  static readonly layout = borsh.struct([borsh.u64("lamports")])
+
+  constructor(fields: BatchOrderFields) {
+    this.lamports = fields.lamports
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<BatchOrder | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<BatchOrder | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): BatchOrder {
+    if (!data.slice(0, 8).equals(BatchOrder.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = BatchOrder.layout.decode(data.slice(8))
+
+    return new BatchOrder({
+      lamports: dec.lamports,
+    })
+  }
+
+  toJSON(): BatchOrderJSON {
+    return {
+      lamports: this.lamports.toString(),
+    }
+  }
+
+  static fromJSON(obj: BatchOrderJSON): BatchOrder {
+    return new BatchOrder({
+      lamports: new BN(obj.lamports),
+    })
+  }
+}
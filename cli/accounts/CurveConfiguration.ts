@@ -1,27 +1,100 @@
 import { PublicKey, Connection } from "@solana/web3.js"
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface CurveConfigurationFields {
-  fees: number
+  buyFeeBps: number
+  sellFeeBps: number
+  initialVirtualSolReserves: BN
+  initialVirtualTokenReserves: BN
+  completionThreshold: types.CompletionThresholdKind
+  admin: PublicKey
+  treasury: PublicKey
+  pendingAdmin: PublicKey | null
+  pause: types.PauseStateKind
+  multisigSigners: Array<PublicKey>
+  multisigThreshold: number
+  multisigEpoch: BN
+  proposalCount: BN
+  minUpdateDelay: BN
+  raydiumAmmConfig: PublicKey
 }
 
 export interface CurveConfigurationJSON {
-  fees: number
+  buyFeeBps: number
+  sellFeeBps: number
+  initialVirtualSolReserves: string
+  initialVirtualTokenReserves: string
+  completionThreshold: types.CompletionThresholdJSON
+  admin: string
+  treasury: string
+  pendingAdmin: string | null
+  pause: types.PauseStateJSON
+  multisigSigners: Array<string>
+  multisigThreshold: number
+  multisigEpoch: string
+  proposalCount: string
+  minUpdateDelay: string
+  raydiumAmmConfig: string
 }
 
 export class CurveConfiguration {
-  readonly fees: number
+  readonly buyFeeBps: number
+  readonly sellFeeBps: number
+  readonly initialVirtualSolReserves: BN
+  readonly initialVirtualTokenReserves: BN
+  readonly completionThreshold: types.CompletionThresholdKind
+  readonly admin: PublicKey
+  readonly treasury: PublicKey
+  readonly pendingAdmin: PublicKey | null
+  readonly pause: types.PauseStateKind
+  readonly multisigSigners: Array<PublicKey>
+  readonly multisigThreshold: number
+  readonly multisigEpoch: BN
+  readonly proposalCount: BN
+  readonly minUpdateDelay: BN
+  readonly raydiumAmmConfig: PublicKey
 
   static readonly discriminator = Buffer.from([
     225, 242, 252, 198, 63, 77, 56, 255,
   ])
 
-  static readonly layout = borsh.struct([borsh.f64("fees")])
+  static readonly layout = borsh.struct([
+    borsh.u16("buyFeeBps"),
+    borsh.u16("sellFeeBps"),
+    borsh.u64("initialVirtualSolReserves"),
+    borsh.u64("initialVirtualTokenReserves"),
+    types.CompletionThreshold.layout("completionThreshold"),
+    borsh.publicKey("admin"),
+    borsh.publicKey("treasury"),
+    borsh.option(borsh.publicKey(), "pendingAdmin"),
+    types.PauseState.layout("pause"),
+    borsh.vec(borsh.publicKey(), "multisigSigners"),
+    borsh.u8("multisigThreshold"),
+    borsh.u64("multisigEpoch"),
+    borsh.u64("proposalCount"),
+    borsh.i64("minUpdateDelay"),
+    borsh.publicKey("raydiumAmmConfig"),
+  ])
 
   constructor(fields: CurveConfigurationFields) {
-    this.fees = fields.fees
+    this.buyFeeBps = fields.buyFeeBps
+    this.sellFeeBps = fields.sellFeeBps
+    this.initialVirtualSolReserves = fields.initialVirtualSolReserves
+    this.initialVirtualTokenReserves = fields.initialVirtualTokenReserves
+    this.completionThreshold = fields.completionThreshold
+    this.admin = fields.admin
+    this.treasury = fields.treasury
+    this.pendingAdmin = fields.pendingAdmin
+    this.pause = fields.pause
+    this.multisigSigners = fields.multisigSigners
+    this.multisigThreshold = fields.multisigThreshold
+    this.multisigEpoch = fields.multisigEpoch
+    this.proposalCount = fields.proposalCount
+    this.minUpdateDelay = fields.minUpdateDelay
+    this.raydiumAmmConfig = fields.raydiumAmmConfig
   }
 
   static async fetch(
@@ -68,19 +141,64 @@ export class CurveConfiguration {
     const dec = CurveConfiguration.layout.decode(data.slice(8))
 
     return new CurveConfiguration({
-      fees: dec.fees,
+      buyFeeBps: dec.buyFeeBps,
+      sellFeeBps: dec.sellFeeBps,
+      initialVirtualSolReserves: dec.initialVirtualSolReserves,
+      initialVirtualTokenReserves: dec.initialVirtualTokenReserves,
+      completionThreshold:
+        types.CompletionThreshold.fromDecoded(dec.completionThreshold),
+      admin: dec.admin,
+      treasury: dec.treasury,
+      pendingAdmin: dec.pendingAdmin,
+      pause: types.PauseState.fromDecoded(dec.pause),
+      multisigSigners: dec.multisigSigners,
+      multisigThreshold: dec.multisigThreshold,
+      multisigEpoch: dec.multisigEpoch,
+      proposalCount: dec.proposalCount,
+      minUpdateDelay: dec.minUpdateDelay,
+      raydiumAmmConfig: dec.raydiumAmmConfig,
     })
   }
 
   toJSON(): CurveConfigurationJSON {
     return {
-      fees: this.fees,
+      buyFeeBps: this.buyFeeBps,
+      sellFeeBps: this.sellFeeBps,
+      initialVirtualSolReserves: this.initialVirtualSolReserves.toString(),
+      initialVirtualTokenReserves: this.initialVirtualTokenReserves.toString(),
+      completionThreshold: this.completionThreshold.toJSON(),
+      admin: this.admin.toString(),
+      treasury: this.treasury.toString(),
+      pendingAdmin: (this.pendingAdmin && this.pendingAdmin.toString()) || null,
+      pause: this.pause.toJSON(),
+      multisigSigners: this.multisigSigners.map((item) => item.toString()),
+      multisigThreshold: this.multisigThreshold,
+      multisigEpoch: this.multisigEpoch.toString(),
+      proposalCount: this.proposalCount.toString(),
+      minUpdateDelay: this.minUpdateDelay.toString(),
+      raydiumAmmConfig: this.raydiumAmmConfig.toString(),
     }
   }
 
   static fromJSON(obj: CurveConfigurationJSON): CurveConfiguration {
     return new CurveConfiguration({
-      fees: obj.fees,
+      buyFeeBps: obj.buyFeeBps,
+      sellFeeBps: obj.sellFeeBps,
+      initialVirtualSolReserves: new BN(obj.initialVirtualSolReserves),
+      initialVirtualTokenReserves: new BN(obj.initialVirtualTokenReserves),
+      completionThreshold:
+        types.CompletionThreshold.fromJSON(obj.completionThreshold),
+      admin: new PublicKey(obj.admin),
+      treasury: new PublicKey(obj.treasury),
+      pendingAdmin:
+        (obj.pendingAdmin && new PublicKey(obj.pendingAdmin)) || null,
+      pause: types.PauseState.fromJSON(obj.pause),
+      multisigSigners: obj.multisigSigners.map((item) => new PublicKey(item)),
+      multisigThreshold: obj.multisigThreshold,
+      multisigEpoch: new BN(obj.multisigEpoch),
+      proposalCount: new BN(obj.proposalCount),
+      minUpdateDelay: new BN(obj.minUpdateDelay),
+      raydiumAmmConfig: new PublicKey(obj.raydiumAmmConfig),
     })
   }
 }
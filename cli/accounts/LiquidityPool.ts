@@ -1,33 +1,67 @@
 import { PublicKey, Connection } from "@solana/web3.js"
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface LiquidityPoolFields {
   tokenOne: PublicKey
-  tokenTwo: PublicKey
   totalSupply: BN
   reserveOne: BN
   reserveTwo: BN
   bump: number
+  solVaultBump: number
+  virtualSolReserves: BN
+  virtualTokenReserves: BN
+  curveType: types.CurveTypeKind
+  curveParams: Array<BN>
+  tokensSold: BN
+  status: types.PoolStatusKind
+  migrationTarget: types.MigrationTargetKind
+  presale: types.PresaleFields
+  auction: types.DutchAuctionFields
+  batchAuction: types.BatchAuctionFields
+  pause: types.PauseStateKind
 }
 
 export interface LiquidityPoolJSON {
   tokenOne: string
-  tokenTwo: string
   totalSupply: string
   reserveOne: string
   reserveTwo: string
   bump: number
+  solVaultBump: number
+  virtualSolReserves: string
+  virtualTokenReserves: string
+  curveType: types.CurveTypeJSON
+  curveParams: Array<string>
+  tokensSold: string
+  status: types.PoolStatusJSON
+  migrationTarget: types.MigrationTargetJSON
+  presale: types.PresaleJSON
+  auction: types.DutchAuctionJSON
+  batchAuction: types.BatchAuctionJSON
+  pause: types.PauseStateJSON
 }
 
 export class LiquidityPool {
   readonly tokenOne: PublicKey
-  readonly tokenTwo: PublicKey
   readonly totalSupply: BN
   readonly reserveOne: BN
   readonly reserveTwo: BN
   readonly bump: number
+  readonly solVaultBump: number
+  readonly virtualSolReserves: BN
+  readonly virtualTokenReserves: BN
+  readonly curveType: types.CurveTypeKind
+  readonly curveParams: Array<BN>
+  readonly tokensSold: BN
+  readonly status: types.PoolStatusKind
+  readonly migrationTarget: types.MigrationTargetKind
+  readonly presale: types.Presale
+  readonly auction: types.DutchAuction
+  readonly batchAuction: types.BatchAuction
+  readonly pause: types.PauseStateKind
 
   static readonly discriminator = Buffer.from([
     66, 38, 17, 64, 188, 80, 68, 129,
@@ -35,20 +69,42 @@ export class LiquidityPool {
 
   static readonly layout = borsh.struct([
     borsh.publicKey("tokenOne"),
-    borsh.publicKey("tokenTwo"),
     borsh.u64("totalSupply"),
     borsh.u64("reserveOne"),
     borsh.u64("reserveTwo"),
     borsh.u8("bump"),
+    borsh.u8("solVaultBump"),
+    borsh.u64("virtualSolReserves"),
+    borsh.u64("virtualTokenReserves"),
+    types.CurveType.layout("curveType"),
+    borsh.array(borsh.u64(), 3, "curveParams"),
+    borsh.u64("tokensSold"),
+    types.PoolStatus.layout("status"),
+    types.MigrationTarget.layout("migrationTarget"),
+    types.Presale.layout("presale"),
+    types.DutchAuction.layout("auction"),
+    types.BatchAuction.layout("batchAuction"),
+    types.PauseState.layout("pause"),
   ])
 
   constructor(fields: LiquidityPoolFields) {
     this.tokenOne = fields.tokenOne
-    this.tokenTwo = fields.tokenTwo
     this.totalSupply = fields.totalSupply
     this.reserveOne = fields.reserveOne
     this.reserveTwo = fields.reserveTwo
     this.bump = fields.bump
+    this.solVaultBump = fields.solVaultBump
+    this.virtualSolReserves = fields.virtualSolReserves
+    this.virtualTokenReserves = fields.virtualTokenReserves
+    this.curveType = fields.curveType
+    this.curveParams = fields.curveParams
+    this.tokensSold = fields.tokensSold
+    this.status = fields.status
+    this.migrationTarget = fields.migrationTarget
+    this.presale = new types.Presale({ ...fields.presale })
+    this.auction = new types.DutchAuction({ ...fields.auction })
+    this.batchAuction = new types.BatchAuction({ ...fields.batchAuction })
+    this.pause = fields.pause
   }
 
   static async fetch(
@@ -96,33 +152,66 @@ export class LiquidityPool {
 
     return new LiquidityPool({
       tokenOne: dec.tokenOne,
-      tokenTwo: dec.tokenTwo,
       totalSupply: dec.totalSupply,
       reserveOne: dec.reserveOne,
       reserveTwo: dec.reserveTwo,
       bump: dec.bump,
+      solVaultBump: dec.solVaultBump,
+      virtualSolReserves: dec.virtualSolReserves,
+      virtualTokenReserves: dec.virtualTokenReserves,
+      curveType: types.CurveType.fromDecoded(dec.curveType),
+      curveParams: dec.curveParams,
+      tokensSold: dec.tokensSold,
+      status: types.PoolStatus.fromDecoded(dec.status),
+      migrationTarget: types.MigrationTarget.fromDecoded(dec.migrationTarget),
+      presale: types.Presale.fromDecoded(dec.presale),
+      auction: types.DutchAuction.fromDecoded(dec.auction),
+      batchAuction: types.BatchAuction.fromDecoded(dec.batchAuction),
+      pause: types.PauseState.fromDecoded(dec.pause),
     })
   }
 
   toJSON(): LiquidityPoolJSON {
     return {
       tokenOne: this.tokenOne.toString(),
-      tokenTwo: this.tokenTwo.toString(),
       totalSupply: this.totalSupply.toString(),
       reserveOne: this.reserveOne.toString(),
       reserveTwo: this.reserveTwo.toString(),
       bump: this.bump,
+      solVaultBump: this.solVaultBump,
+      virtualSolReserves: this.virtualSolReserves.toString(),
+      virtualTokenReserves: this.virtualTokenReserves.toString(),
+      curveType: this.curveType.toJSON(),
+      curveParams: this.curveParams.map((item) => item.toString()),
+      tokensSold: this.tokensSold.toString(),
+      status: this.status.toJSON(),
+      migrationTarget: this.migrationTarget.toJSON(),
+      presale: this.presale.toJSON(),
+      auction: this.auction.toJSON(),
+      batchAuction: this.batchAuction.toJSON(),
+      pause: this.pause.toJSON(),
     }
   }
 
   static fromJSON(obj: LiquidityPoolJSON): LiquidityPool {
     return new LiquidityPool({
       tokenOne: new PublicKey(obj.tokenOne),
-      tokenTwo: new PublicKey(obj.tokenTwo),
       totalSupply: new BN(obj.totalSupply),
       reserveOne: new BN(obj.reserveOne),
       reserveTwo: new BN(obj.reserveTwo),
       bump: obj.bump,
+      solVaultBump: obj.solVaultBump,
+      virtualSolReserves: new BN(obj.virtualSolReserves),
+      virtualTokenReserves: new BN(obj.virtualTokenReserves),
+      curveType: types.CurveType.fromJSON(obj.curveType),
+      curveParams: obj.curveParams.map((item) => new BN(item)),
+      tokensSold: new BN(obj.tokensSold),
+      status: types.PoolStatus.fromJSON(obj.status),
+      migrationTarget: types.MigrationTarget.fromJSON(obj.migrationTarget),
+      presale: types.Presale.fromJSON(obj.presale),
+      auction: types.DutchAuction.fromJSON(obj.auction),
+      batchAuction: types.BatchAuction.fromJSON(obj.batchAuction),
+      pause: types.PauseState.fromJSON(obj.pause),
     })
   }
 }
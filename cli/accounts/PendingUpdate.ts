@@ -0,0 +1,105 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PendingUpdateFields {
+  update: types.ConfigUpdateFields
+  effectiveAt: BN
+  payer: PublicKey
+}
+
+export interface PendingUpdateJSON {
+  update: types.ConfigUpdateJSON
+  effectiveAt: string
+  payer: string
+}
+
+export class PendingUpdate {
+  readonly update: types.ConfigUpdate
+  readonly effectiveAt: BN
+  readonly payer: PublicKey
+
+  static readonly discriminator = Buffer.from([
+    24, 212, 61, 73, 130, 111, 15, 112,
+  ])
+
+  static readonly layout = borsh.struct([
+    types.ConfigUpdate.layout("update"),
+    borsh.i64("effectiveAt"),
+    borsh.publicKey("payer"),
+  ])
+
+  constructor(fields: PendingUpdateFields) {
+    this.update = new types.ConfigUpdate({ ...fields.update })
+    this.effectiveAt = fields.effectiveAt
+    this.payer = fields.payer
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PendingUpdate | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PendingUpdate | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PendingUpdate {
+    if (!data.slice(0, 8).equals(PendingUpdate.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PendingUpdate.layout.decode(data.slice(8))
+
+    return new PendingUpdate({
+      update: types.ConfigUpdate.fromDecoded(dec.update),
+      effectiveAt: dec.effectiveAt,
+      payer: dec.payer,
+    })
+  }
+
+  toJSON(): PendingUpdateJSON {
+    return {
+      update: this.update.toJSON(),
+      effectiveAt: this.effectiveAt.toString(),
+      payer: this.payer.toString(),
+    }
+  }
+
+  static fromJSON(obj: PendingUpdateJSON): PendingUpdate {
+    return new PendingUpdate({
+      update: types.ConfigUpdate.fromJSON(obj.update),
+      effectiveAt: new BN(obj.effectiveAt),
+      payer: new PublicKey(obj.payer),
+    })
+  }
+}
@@ -0,0 +1,87 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PresaleBidFields {
+  amount: BN
+}
+
+export interface PresaleBidJSON {
+  amount: string
+}
+
+export class PresaleBid {
+  readonly amount: BN
+
+  static readonly discriminator = Buffer.from([
+    22, 53, 156, 151, 66, 197, 17, 90,
+  ])
+
+  static readonly layout = borsh.struct([borsh.u64("amount")])
+
+  constructor(fields: PresaleBidFields) {
+    this.amount = fields.amount
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PresaleBid | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PresaleBid | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PresaleBid {
+    if (!data.slice(0, 8).equals(PresaleBid.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PresaleBid.layout.decode(data.slice(8))
+
+    return new PresaleBid({
+      amount: dec.amount,
+    })
+  }
+
+  toJSON(): PresaleBidJSON {
+    return {
+      amount: this.amount.toString(),
+    }
+  }
+
+  static fromJSON(obj: PresaleBidJSON): PresaleBid {
+    return new PresaleBid({
+      amount: new BN(obj.amount),
+    })
+  }
+}
@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProposalFields {
+  id: BN
+  proposer: PublicKey
+  action: types.AdminActionKind
+  approvals: Array<PublicKey>
+  executed: boolean
+  epoch: BN
+  expiresAt: BN
+}
+
+export interface ProposalJSON {
+  id: string
+  proposer: string
+  action: types.AdminActionJSON
+  approvals: Array<string>
+  executed: boolean
+  epoch: string
+  expiresAt: string
+}
+
+export class Proposal {
+  readonly id: BN
+  readonly proposer: PublicKey
+  readonly action: types.AdminActionKind
+  readonly approvals: Array<PublicKey>
+  readonly executed: boolean
+  readonly epoch: BN
+  readonly expiresAt: BN
+
+  static readonly discriminator = Buffer.from([
+    26, 94, 189, 187, 116, 136, 53, 33,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("id"),
+    borsh.publicKey("proposer"),
+    types.AdminAction.layout("action"),
+    borsh.vec(borsh.publicKey(), "approvals"),
+    borsh.bool("executed"),
+    borsh.u64("epoch"),
+    borsh.i64("expiresAt"),
+  ])
+
+  constructor(fields: ProposalFields) {
+    this.id = fields.id
+    this.proposer = fields.proposer
+    this.action = fields.action
+    this.approvals = fields.approvals
+    this.executed = fields.executed
+    this.epoch = fields.epoch
+    this.expiresAt = fields.expiresAt
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Proposal | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Proposal | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Proposal {
+    if (!data.slice(0, 8).equals(Proposal.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Proposal.layout.decode(data.slice(8))
+
+    return new Proposal({
+      id: dec.id,
+      proposer: dec.proposer,
+      action: types.AdminAction.fromDecoded(dec.action),
+      approvals: dec.approvals,
+      executed: dec.executed,
+      epoch: dec.epoch,
+      expiresAt: dec.expiresAt,
+    })
+  }
+
+  toJSON(): ProposalJSON {
+    return {
+      id: this.id.toString(),
+      proposer: this.proposer.toString(),
+      action: this.action.toJSON(),
+      approvals: this.approvals.map((item) => item.toString()),
+      executed: this.executed,
+      epoch: this.epoch.toString(),
+      expiresAt: this.expiresAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: ProposalJSON): Proposal {
+    return new Proposal({
+      id: new BN(obj.id),
+      proposer: new PublicKey(obj.proposer),
+      action: types.AdminAction.fromJSON(obj.action),
+      approvals: obj.approvals.map((item) => new PublicKey(item)),
+      executed: obj.executed,
+      epoch: new BN(obj.epoch),
+      expiresAt: new BN(obj.expiresAt),
+    })
+  }
+}
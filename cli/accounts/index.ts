@@ -8,5 +8,13 @@ export type {
   LiquidityProviderFields,
   LiquidityProviderJSON,
 } from "./LiquidityProvider"
+export { PresaleBid } from "./PresaleBid"
+export type { PresaleBidFields, PresaleBidJSON } from "./PresaleBid"
+export { PendingUpdate } from "./PendingUpdate"
+export type { PendingUpdateFields, PendingUpdateJSON } from "./PendingUpdate"
+export { Proposal } from "./Proposal"
+export type { ProposalFields, ProposalJSON } from "./Proposal"
+export { BatchOrder } from "./BatchOrder"
+export type { BatchOrderFields, BatchOrderJSON } from "./BatchOrder"
 export { LiquidityPool } from "./LiquidityPool"
 export type { LiquidityPoolFields, LiquidityPoolJSON } from "./LiquidityPool"
@@ -9,6 +9,47 @@ export type CustomError =
   | FailedToAddLiquidity
   | FailedToRemoveLiquidity
   | OverflowOrUnderflowOccurred
+  | InvalidConfigurationAccount
+  | SlippageExceeded
+  | ExcessiveInputAmount
+  | InvalidTradeDirection
+  | VaultBelowRentExemption
+  | InvalidCurveParameters
+  | InvalidCompletionThreshold
+  | PoolNotActive
+  | PoolNotCompleted
+  | InvalidMigrationTarget
+  | InvalidPresaleParameters
+  | PresaleNotOpen
+  | PresaleNotEnded
+  | PresaleNotLaunched
+  | PresaleNotRefundable
+  | NotAllowlisted
+  | PresaleAllocationExceeded
+  | InvalidAuctionParameters
+  | AuctionNotOpen
+  | AuctionNotEnded
+  | InvalidBatchAuctionParameters
+  | BatchAuctionNotOpen
+  | BatchAuctionNotEnded
+  | NoPendingAdmin
+  | NotPendingAdmin
+  | Paused
+  | InvalidMultisig
+  | MultisigNotEnabled
+  | NotMultisigSigner
+  | AlreadyApproved
+  | ProposalAlreadyExecuted
+  | NotEnoughApprovals
+  | InvalidProposalAccounts
+  | InvalidUpdateDelay
+  | UpdateNotEffective
+  | UnauthorizedPoolCreator
+  | ExactOutputUnavailable
+  | NotUpgradeAuthority
+  | ProposalExpired
+  | ProposalStale
+  | ProposalStillOpen
 
 export class DuplicateTokenNotAllowed extends Error {
   static readonly code = 6000
@@ -120,6 +161,460 @@ export class OverflowOrUnderflowOccurred extends Error {
   }
 }
 
+export class InvalidConfigurationAccount extends Error {
+  static readonly code = 6010
+  readonly code = 6010
+  readonly name = "InvalidConfigurationAccount"
+  readonly msg = "Invalid configuration account"
+
+  constructor(readonly logs?: string[]) {
+    super("6010: Invalid configuration account")
+  }
+}
+
+export class SlippageExceeded extends Error {
+  static readonly code = 6011
+  readonly code = 6011
+  readonly name = "SlippageExceeded"
+  readonly msg = "Output amount is below the minimum requested"
+
+  constructor(readonly logs?: string[]) {
+    super("6011: Output amount is below the minimum requested")
+  }
+}
+
+export class ExcessiveInputAmount extends Error {
+  static readonly code = 6012
+  readonly code = 6012
+  readonly name = "ExcessiveInputAmount"
+  readonly msg = "Input amount is above the maximum allowed"
+
+  constructor(readonly logs?: string[]) {
+    super("6012: Input amount is above the maximum allowed")
+  }
+}
+
+export class InvalidTradeDirection extends Error {
+  static readonly code = 6013
+  readonly code = 6013
+  readonly name = "InvalidTradeDirection"
+  readonly msg = "Invalid trade direction"
+
+  constructor(readonly logs?: string[]) {
+    super("6013: Invalid trade direction")
+  }
+}
+
+export class VaultBelowRentExemption extends Error {
+  static readonly code = 6014
+  readonly code = 6014
+  readonly name = "VaultBelowRentExemption"
+  readonly msg = "SOL vault would fall below its rent-exempt minimum"
+
+  constructor(readonly logs?: string[]) {
+    super("6014: SOL vault would fall below its rent-exempt minimum")
+  }
+}
+
+export class InvalidCurveParameters extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "InvalidCurveParameters"
+  readonly msg = "Invalid curve parameters"
+
+  constructor(readonly logs?: string[]) {
+    super("6015: Invalid curve parameters")
+  }
+}
+
+export class InvalidCompletionThreshold extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "InvalidCompletionThreshold"
+  readonly msg = "Invalid completion threshold"
+
+  constructor(readonly logs?: string[]) {
+    super("6016: Invalid completion threshold")
+  }
+}
+
+export class PoolNotActive extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "PoolNotActive"
+  readonly msg = "Pool is not accepting trades"
+
+  constructor(readonly logs?: string[]) {
+    super("6017: Pool is not accepting trades")
+  }
+}
+
+export class PoolNotCompleted extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "PoolNotCompleted"
+  readonly msg = "Pool curve has not completed"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Pool curve has not completed")
+  }
+}
+
+export class InvalidMigrationTarget extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "InvalidMigrationTarget"
+  readonly msg = "Pool was launched for a different migration target"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: Pool was launched for a different migration target")
+  }
+}
+
+export class InvalidPresaleParameters extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "InvalidPresaleParameters"
+  readonly msg = "Invalid presale parameters"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: Invalid presale parameters")
+  }
+}
+
+export class PresaleNotOpen extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "PresaleNotOpen"
+  readonly msg = "Presale is not accepting bids"
+
+  constructor(readonly logs?: string[]) {
+    super("6021: Presale is not accepting bids")
+  }
+}
+
+export class PresaleNotEnded extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "PresaleNotEnded"
+  readonly msg = "Presale window has not ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6022: Presale window has not ended")
+  }
+}
+
+export class PresaleNotLaunched extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "PresaleNotLaunched"
+  readonly msg = "Presale has not launched"
+
+  constructor(readonly logs?: string[]) {
+    super("6023: Presale has not launched")
+  }
+}
+
+export class PresaleNotRefundable extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "PresaleNotRefundable"
+  readonly msg = "Presale bids are only refunded once the presale is cancelled"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: Presale bids are only refunded once the presale is cancelled")
+  }
+}
+
+export class NotAllowlisted extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "NotAllowlisted"
+  readonly msg = "Wallet is not on the presale allowlist"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: Wallet is not on the presale allowlist")
+  }
+}
+
+export class PresaleAllocationExceeded extends Error {
+  static readonly code = 6026
+  readonly code = 6026
+  readonly name = "PresaleAllocationExceeded"
+  readonly msg = "Deposit would exceed the wallet's presale allocation"
+
+  constructor(readonly logs?: string[]) {
+    super("6026: Deposit would exceed the wallet's presale allocation")
+  }
+}
+
+export class InvalidAuctionParameters extends Error {
+  static readonly code = 6027
+  readonly code = 6027
+  readonly name = "InvalidAuctionParameters"
+  readonly msg = "Invalid auction parameters"
+
+  constructor(readonly logs?: string[]) {
+    super("6027: Invalid auction parameters")
+  }
+}
+
+export class AuctionNotOpen extends Error {
+  static readonly code = 6028
+  readonly code = 6028
+  readonly name = "AuctionNotOpen"
+  readonly msg = "Auction is not selling"
+
+  constructor(readonly logs?: string[]) {
+    super("6028: Auction is not selling")
+  }
+}
+
+export class AuctionNotEnded extends Error {
+  static readonly code = 6029
+  readonly code = 6029
+  readonly name = "AuctionNotEnded"
+  readonly msg = "Auction window has not ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6029: Auction window has not ended")
+  }
+}
+
+export class InvalidBatchAuctionParameters extends Error {
+  static readonly code = 6030
+  readonly code = 6030
+  readonly name = "InvalidBatchAuctionParameters"
+  readonly msg = "Invalid batch auction parameters"
+
+  constructor(readonly logs?: string[]) {
+    super("6030: Invalid batch auction parameters")
+  }
+}
+
+export class BatchAuctionNotOpen extends Error {
+  static readonly code = 6031
+  readonly code = 6031
+  readonly name = "BatchAuctionNotOpen"
+  readonly msg = "Batch auction is not taking orders"
+
+  constructor(readonly logs?: string[]) {
+    super("6031: Batch auction is not taking orders")
+  }
+}
+
+export class BatchAuctionNotEnded extends Error {
+  static readonly code = 6032
+  readonly code = 6032
+  readonly name = "BatchAuctionNotEnded"
+  readonly msg = "Batch auction window has not ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6032: Batch auction window has not ended")
+  }
+}
+
+export class NoPendingAdmin extends Error {
+  static readonly code = 6033
+  readonly code = 6033
+  readonly name = "NoPendingAdmin"
+  readonly msg = "No admin handover is pending"
+
+  constructor(readonly logs?: string[]) {
+    super("6033: No admin handover is pending")
+  }
+}
+
+export class NotPendingAdmin extends Error {
+  static readonly code = 6034
+  readonly code = 6034
+  readonly name = "NotPendingAdmin"
+  readonly msg = "Signer is not the pending admin"
+
+  constructor(readonly logs?: string[]) {
+    super("6034: Signer is not the pending admin")
+  }
+}
+
+export class Paused extends Error {
+  static readonly code = 6035
+  readonly code = 6035
+  readonly name = "Paused"
+  readonly msg = "Paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6035: Paused")
+  }
+}
+
+export class InvalidMultisig extends Error {
+  static readonly code = 6036
+  readonly code = 6036
+  readonly name = "InvalidMultisig"
+  readonly msg = "Invalid multisig signers or threshold"
+
+  constructor(readonly logs?: string[]) {
+    super("6036: Invalid multisig signers or threshold")
+  }
+}
+
+export class MultisigNotEnabled extends Error {
+  static readonly code = 6037
+  readonly code = 6037
+  readonly name = "MultisigNotEnabled"
+  readonly msg = "Configuration is not governed by a multisig"
+
+  constructor(readonly logs?: string[]) {
+    super("6037: Configuration is not governed by a multisig")
+  }
+}
+
+export class NotMultisigSigner extends Error {
+  static readonly code = 6038
+  readonly code = 6038
+  readonly name = "NotMultisigSigner"
+  readonly msg = "Signer is not in the multisig"
+
+  constructor(readonly logs?: string[]) {
+    super("6038: Signer is not in the multisig")
+  }
+}
+
+export class AlreadyApproved extends Error {
+  static readonly code = 6039
+  readonly code = 6039
+  readonly name = "AlreadyApproved"
+  readonly msg = "Signer already approved this proposal"
+
+  constructor(readonly logs?: string[]) {
+    super("6039: Signer already approved this proposal")
+  }
+}
+
+export class ProposalAlreadyExecuted extends Error {
+  static readonly code = 6040
+  readonly code = 6040
+  readonly name = "ProposalAlreadyExecuted"
+  readonly msg = "Proposal was already executed"
+
+  constructor(readonly logs?: string[]) {
+    super("6040: Proposal was already executed")
+  }
+}
+
+export class NotEnoughApprovals extends Error {
+  static readonly code = 6041
+  readonly code = 6041
+  readonly name = "NotEnoughApprovals"
+  readonly msg = "Proposal does not have enough approvals"
+
+  constructor(readonly logs?: string[]) {
+    super("6041: Proposal does not have enough approvals")
+  }
+}
+
+export class InvalidProposalAccounts extends Error {
+  static readonly code = 6042
+  readonly code = 6042
+  readonly name = "InvalidProposalAccounts"
+  readonly msg = "Accounts do not match the proposal"
+
+  constructor(readonly logs?: string[]) {
+    super("6042: Accounts do not match the proposal")
+  }
+}
+
+export class InvalidUpdateDelay extends Error {
+  static readonly code = 6043
+  readonly code = 6043
+  readonly name = "InvalidUpdateDelay"
+  readonly msg = "Invalid update delay"
+
+  constructor(readonly logs?: string[]) {
+    super("6043: Invalid update delay")
+  }
+}
+
+export class UpdateNotEffective extends Error {
+  static readonly code = 6044
+  readonly code = 6044
+  readonly name = "UpdateNotEffective"
+  readonly msg = "Queued update is not effective yet"
+
+  constructor(readonly logs?: string[]) {
+    super("6044: Queued update is not effective yet")
+  }
+}
+
+export class UnauthorizedPoolCreator extends Error {
+  static readonly code = 6045
+  readonly code = 6045
+  readonly name = "UnauthorizedPoolCreator"
+  readonly msg = "Only the mint authority or the admin can create its pool"
+
+  constructor(readonly logs?: string[]) {
+    super("6045: Only the mint authority or the admin can create its pool")
+  }
+}
+
+export class ExactOutputUnavailable extends Error {
+  static readonly code = 6046
+  readonly code = 6046
+  readonly name = "ExactOutputUnavailable"
+  readonly msg = "Exact output exceeds the supply left before completion"
+
+  constructor(readonly logs?: string[]) {
+    super("6046: Exact output exceeds the supply left before completion")
+  }
+}
+
+export class NotUpgradeAuthority extends Error {
+  static readonly code = 6047
+  readonly code = 6047
+  readonly name = "NotUpgradeAuthority"
+  readonly msg =
+    "Only the program's upgrade authority can migrate the configuration"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6047: Only the program's upgrade authority can migrate the configuration"
+    )
+  }
+}
+
+export class ProposalExpired extends Error {
+  static readonly code = 6048
+  readonly code = 6048
+  readonly name = "ProposalExpired"
+  readonly msg = "Proposal has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6048: Proposal has expired")
+  }
+}
+
+export class ProposalStale extends Error {
+  static readonly code = 6049
+  readonly code = 6049
+  readonly name = "ProposalStale"
+  readonly msg = "Proposal was created for a previous signer set"
+
+  constructor(readonly logs?: string[]) {
+    super("6049: Proposal was created for a previous signer set")
+  }
+}
+
+export class ProposalStillOpen extends Error {
+  static readonly code = 6050
+  readonly code = 6050
+  readonly name = "ProposalStillOpen"
+  readonly msg = "Proposal is still open"
+
+  constructor(readonly logs?: string[]) {
+    super("6050: Proposal is still open")
+  }
+}
+
 export function fromCode(code: number, logs?: string[]): CustomError | null {
   switch (code) {
     case 6000:
@@ -142,6 +637,88 @@ export function fromCode(code: number, logs?: string[]): CustomError | null {
       return new FailedToRemoveLiquidity(logs)
     case 6009:
       return new OverflowOrUnderflowOccurred(logs)
+    case 6010:
+      return new InvalidConfigurationAccount(logs)
+    case 6011:
+      return new SlippageExceeded(logs)
+    case 6012:
+      return new ExcessiveInputAmount(logs)
+    case 6013:
+      return new InvalidTradeDirection(logs)
+    case 6014:
+      return new VaultBelowRentExemption(logs)
+    case 6015:
+      return new InvalidCurveParameters(logs)
+    case 6016:
+      return new InvalidCompletionThreshold(logs)
+    case 6017:
+      return new PoolNotActive(logs)
+    case 6018:
+      return new PoolNotCompleted(logs)
+    case 6019:
+      return new InvalidMigrationTarget(logs)
+    case 6020:
+      return new InvalidPresaleParameters(logs)
+    case 6021:
+      return new PresaleNotOpen(logs)
+    case 6022:
+      return new PresaleNotEnded(logs)
+    case 6023:
+      return new PresaleNotLaunched(logs)
+    case 6024:
+      return new PresaleNotRefundable(logs)
+    case 6025:
+      return new NotAllowlisted(logs)
+    case 6026:
+      return new PresaleAllocationExceeded(logs)
+    case 6027:
+      return new InvalidAuctionParameters(logs)
+    case 6028:
+      return new AuctionNotOpen(logs)
+    case 6029:
+      return new AuctionNotEnded(logs)
+    case 6030:
+      return new InvalidBatchAuctionParameters(logs)
+    case 6031:
+      return new BatchAuctionNotOpen(logs)
+    case 6032:
+      return new BatchAuctionNotEnded(logs)
+    case 6033:
+      return new NoPendingAdmin(logs)
+    case 6034:
+      return new NotPendingAdmin(logs)
+    case 6035:
+      return new Paused(logs)
+    case 6036:
+      return new InvalidMultisig(logs)
+    case 6037:
+      return new MultisigNotEnabled(logs)
+    case 6038:
+      return new NotMultisigSigner(logs)
+    case 6039:
+      return new AlreadyApproved(logs)
+    case 6040:
+      return new ProposalAlreadyExecuted(logs)
+    case 6041:
+      return new NotEnoughApprovals(logs)
+    case 6042:
+      return new InvalidProposalAccounts(logs)
+    case 6043:
+      return new InvalidUpdateDelay(logs)
+    case 6044:
+      return new UpdateNotEffective(logs)
+    case 6045:
+      return new UnauthorizedPoolCreator(logs)
+    case 6046:
+      return new ExactOutputUnavailable(logs)
+    case 6047:
+      return new NotUpgradeAuthority(logs)
+    case 6048:
+      return new ProposalExpired(logs)
+    case 6049:
+      return new ProposalStale(logs)
+    case 6050:
+      return new ProposalStillOpen(logs)
   }
 
   return null
@@ -0,0 +1,28 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AcceptAdminAccounts {
+  dexConfigurationAccount: PublicKey
+  newAdmin: PublicKey
+}
+
+export function acceptAdmin(
+  accounts: AcceptAdminAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.newAdmin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([112, 42, 45, 90, 116, 181, 13, 170])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,30 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ApproveProposalAccounts {
+  dexConfigurationAccount: PublicKey
+  proposal: PublicKey
+  signer: PublicKey
+}
+
+export function approveProposal(
+  accounts: ApproveProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.signer, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([136, 108, 102, 85, 98, 114, 7, 147])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,64 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AuctionBuyArgs {
+  amount: BN
+  maxSolIn: BN
+}
+
+export interface AuctionBuyAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  solVault: PublicKey
+  mintTokenOne: PublicKey
+  poolTokenAccountOne: PublicKey
+  userTokenAccountOne: PublicKey
+  user: PublicKey
+  systemProgram: PublicKey
+  tokenProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount"), borsh.u64("maxSolIn")])
+
+export function auctionBuy(
+  args: AuctionBuyArgs,
+  accounts: AuctionBuyAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.userTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.user, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([207, 135, 223, 131, 4, 161, 201, 204])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+      maxSolIn: args.maxSolIn,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,50 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimOrderAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  solVault: PublicKey
+  batchOrder: PublicKey
+  mintTokenOne: PublicKey
+  poolTokenAccountOne: PublicKey
+  userTokenAccountOne: PublicKey
+  user: PublicKey
+  tokenProgram: PublicKey
+  associatedTokenProgram: PublicKey
+  systemProgram: PublicKey
+}
+
+export function claimOrder(
+  accounts: ClaimOrderAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.batchOrder, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.userTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.user, isSigner: true, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([164, 202, 83, 197, 77, 171, 96, 234])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
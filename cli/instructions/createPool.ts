@@ -0,0 +1,73 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreatePoolArgs {
+  curveType: types.CurveTypeKind
+  curveParams: Array<BN>
+  migrationTarget: types.MigrationTargetKind
+  launchMode: types.LaunchModeKind
+}
+
+export interface CreatePoolAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  solVault: PublicKey
+  mintTokenOne: PublicKey
+  poolTokenAccountOne: PublicKey
+  payer: PublicKey
+  rent: PublicKey
+  systemProgram: PublicKey
+  tokenProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.CurveType.layout("curveType"),
+  borsh.array(borsh.u64(), 3, "curveParams"),
+  types.MigrationTarget.layout("migrationTarget"),
+  types.LaunchMode.layout("launchMode"),
+])
+
+export function createPool(
+  args: CreatePoolArgs,
+  accounts: CreatePoolAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([233, 146, 209, 142, 207, 104, 64, 188])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      curveType: args.curveType.toEncodable(),
+      curveParams: args.curveParams,
+      migrationTarget: args.migrationTarget.toEncodable(),
+      launchMode: args.launchMode.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
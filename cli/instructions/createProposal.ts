@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreateProposalArgs {
+  action: types.AdminActionKind
+}
+
+export interface CreateProposalAccounts {
+  dexConfigurationAccount: PublicKey
+  proposal: PublicKey
+  proposer: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.AdminAction.layout("action")])
+
+export function createProposal(
+  args: CreateProposalArgs,
+  accounts: CreateProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.proposer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([132, 116, 68, 174, 216, 160, 198, 22])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      action: args.action.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
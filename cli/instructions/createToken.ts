@@ -0,0 +1,90 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreateTokenArgs {
+  name: string
+  symbol: string
+  uri: string
+  curveType: types.CurveTypeKind
+  curveParams: Array<BN>
+  migrationTarget: types.MigrationTargetKind
+  launchMode: types.LaunchModeKind
+}
+
+export interface CreateTokenAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  solVault: PublicKey
+  mint: PublicKey
+  poolTokenAccountOne: PublicKey
+  metadata: PublicKey
+  payer: PublicKey
+  rent: PublicKey
+  systemProgram: PublicKey
+  tokenProgram: PublicKey
+  associatedTokenProgram: PublicKey
+  tokenMetadataProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.str("name"),
+  borsh.str("symbol"),
+  borsh.str("uri"),
+  types.CurveType.layout("curveType"),
+  borsh.array(borsh.u64(), 3, "curveParams"),
+  types.MigrationTarget.layout("migrationTarget"),
+  types.LaunchMode.layout("launchMode"),
+])
+
+export function createToken(
+  args: CreateTokenArgs,
+  accounts: CreateTokenAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.mint, isSigner: true, isWritable: true },
+    { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.metadata, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: accounts.tokenMetadataProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([84, 52, 204, 228, 24, 140, 234, 75])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      name: args.name,
+      symbol: args.symbol,
+      uri: args.uri,
+      curveType: args.curveType.toEncodable(),
+      curveParams: args.curveParams,
+      migrationTarget: args.migrationTarget.toEncodable(),
+      launchMode: args.launchMode.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,30 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ExecuteConfigUpdateAccounts {
+  dexConfigurationAccount: PublicKey
+  pendingUpdate: PublicKey
+  payer: PublicKey
+}
+
+export function executeConfigUpdate(
+  accounts: ExecuteConfigUpdateAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.pendingUpdate, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([132, 11, 62, 107, 12, 61, 38, 230])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
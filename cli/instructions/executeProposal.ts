@@ -0,0 +1,36 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ExecuteProposalAccounts {
+  dexConfigurationAccount: PublicKey
+  proposal: PublicKey
+  pendingUpdate?: PublicKey
+  executor: PublicKey
+  systemProgram: PublicKey
+}
+
+export function executeProposal(
+  accounts: ExecuteProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    accounts.pendingUpdate
+      ? { pubkey: accounts.pendingUpdate, isSigner: false, isWritable: true }
+      : { pubkey: programId, isSigner: false, isWritable: false },
+    { pubkey: accounts.executor, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([186, 60, 116, 133, 108, 128, 111, 28])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,5 +1,40 @@
 export { initialize } from "./initialize"
 export type { InitializeArgs, InitializeAccounts } from "./initialize"
+export { migrateConfig } from "./migrateConfig"
+export type { MigrateConfigArgs, MigrateConfigAccounts } from "./migrateConfig"
+export { updateConfig } from "./updateConfig"
+export type { UpdateConfigArgs, UpdateConfigAccounts } from "./updateConfig"
+export { executeConfigUpdate } from "./executeConfigUpdate"
+export type { ExecuteConfigUpdateAccounts } from "./executeConfigUpdate"
+export { cancelConfigUpdate } from "./cancelConfigUpdate"
+export type { CancelConfigUpdateAccounts } from "./cancelConfigUpdate"
+export { proposeAdmin } from "./proposeAdmin"
+export type { ProposeAdminArgs, ProposeAdminAccounts } from "./proposeAdmin"
+export { acceptAdmin } from "./acceptAdmin"
+export type { AcceptAdminAccounts } from "./acceptAdmin"
+export { cancelAdminProposal } from "./cancelAdminProposal"
+export type { CancelAdminProposalAccounts } from "./cancelAdminProposal"
+export { setPause } from "./setPause"
+export type { SetPauseArgs, SetPauseAccounts } from "./setPause"
+export { setPoolPause } from "./setPoolPause"
+export type { SetPoolPauseArgs, SetPoolPauseAccounts } from "./setPoolPause"
+export { setMultisig } from "./setMultisig"
+export type { SetMultisigArgs, SetMultisigAccounts } from "./setMultisig"
+export { createProposal } from "./createProposal"
+export type {
+  CreateProposalArgs,
+  CreateProposalAccounts,
+} from "./createProposal"
+export { approveProposal } from "./approveProposal"
+export type { ApproveProposalAccounts } from "./approveProposal"
+export { executeProposal } from "./executeProposal"
+export type { ExecuteProposalAccounts } from "./executeProposal"
+export { closeProposal } from "./closeProposal"
+export type { CloseProposalAccounts } from "./closeProposal"
+export { createPool } from "./createPool"
+export type { CreatePoolArgs, CreatePoolAccounts } from "./createPool"
+export { createToken } from "./createToken"
+export type { CreateTokenArgs, CreateTokenAccounts } from "./createToken"
 export { addLiquidity } from "./addLiquidity"
 export type { AddLiquidityArgs, AddLiquidityAccounts } from "./addLiquidity"
 export { removeLiquidity } from "./removeLiquidity"
@@ -9,3 +44,28 @@ export type {
 } from "./removeLiquidity"
 export { swap } from "./swap"
 export type { SwapArgs, SwapAccounts } from "./swap"
+export { swapExactOut } from "./swapExactOut"
+export type { SwapExactOutArgs, SwapExactOutAccounts } from "./swapExactOut"
+export { migrateToRaydium } from "./migrateToRaydium"
+export type { MigrateToRaydiumAccounts } from "./migrateToRaydium"
+export { migrateToMeteora } from "./migrateToMeteora"
+export type { MigrateToMeteoraAccounts } from "./migrateToMeteora"
+export { reopenPool } from "./reopenPool"
+export type { ReopenPoolAccounts } from "./reopenPool"
+export { presaleDeposit } from "./presaleDeposit"
+export type {
+  PresaleDepositArgs,
+  PresaleDepositAccounts,
+} from "./presaleDeposit"
+export { launch } from "./launch"
+export type { LaunchAccounts } from "./launch"
+export { presaleClaim } from "./presaleClaim"
+export type { PresaleClaimAccounts } from "./presaleClaim"
+export { presaleRefund } from "./presaleRefund"
+export type { PresaleRefundAccounts } from "./presaleRefund"
+export { auctionBuy } from "./auctionBuy"
+export type { AuctionBuyArgs, AuctionBuyAccounts } from "./auctionBuy"
+export { placeOrder } from "./placeOrder"
+export type { PlaceOrderArgs, PlaceOrderAccounts } from "./placeOrder"
+export { claimOrder } from "./claimOrder"
+export type { ClaimOrderAccounts } from "./claimOrder"
@@ -1,20 +1,38 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface InitializeArgs {
-  fee: number
+  buyFeeBps: number
+  sellFeeBps: number
+  initialVirtualSolReserves: BN
+  initialVirtualTokenReserves: BN
+  completionThreshold: types.CompletionThresholdKind
+  minUpdateDelay: BN
+  raydiumAmmConfig: PublicKey
 }
 
 export interface InitializeAccounts {
   dexConfigurationAccount: PublicKey
+  program: PublicKey
+  programData: PublicKey
+  authority: PublicKey
   admin: PublicKey
   rent: PublicKey
   systemProgram: PublicKey
 }
 
-export const layout = borsh.struct([borsh.f64("fee")])
+export const layout = borsh.struct([
+  borsh.u16("buyFeeBps"),
+  borsh.u16("sellFeeBps"),
+  borsh.u64("initialVirtualSolReserves"),
+  borsh.u64("initialVirtualTokenReserves"),
+  types.CompletionThreshold.layout("completionThreshold"),
+  borsh.i64("minUpdateDelay"),
+  borsh.publicKey("raydiumAmmConfig"),
+])
 
 export function initialize(
   args: InitializeArgs,
@@ -27,6 +45,9 @@ export function initialize(
       isSigner: false,
       isWritable: true,
     },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+    { pubkey: accounts.programData, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
     { pubkey: accounts.admin, isSigner: true, isWritable: true },
     { pubkey: accounts.rent, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
@@ -35,7 +56,13 @@ export function initialize(
   const buffer = Buffer.alloc(1000)
   const len = layout.encode(
     {
-      fee: args.fee,
+      buyFeeBps: args.buyFeeBps,
+      sellFeeBps: args.sellFeeBps,
+      initialVirtualSolReserves: args.initialVirtualSolReserves,
+      initialVirtualTokenReserves: args.initialVirtualTokenReserves,
+      completionThreshold: args.completionThreshold.toEncodable(),
+      minUpdateDelay: args.minUpdateDelay,
+      raydiumAmmConfig: args.raydiumAmmConfig,
     },
     buffer
   )
@@ -0,0 +1,50 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrateConfigArgs {
+  minUpdateDelay: BN
+}
+
+export interface MigrateConfigAccounts {
+  dexConfigurationAccount: PublicKey
+  program: PublicKey
+  programData: PublicKey
+  authority: PublicKey
+  payer: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.i64("minUpdateDelay")])
+
+export function migrateConfig(
+  args: MigrateConfigArgs,
+  accounts: MigrateConfigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+    { pubkey: accounts.programData, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([92, 131, 58, 105, 210, 154, 224, 193])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      minUpdateDelay: args.minUpdateDelay,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,86 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrateToMeteoraAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  solVault: PublicKey
+  mintTokenOne: PublicKey
+  poolTokenAccountOne: PublicKey
+  wsolMint: PublicKey
+  payerTokenAccount: PublicKey
+  payerWsolAccount: PublicKey
+  payerLpToken: PublicKey
+  meteoraPool: PublicKey
+  lpMint: PublicKey
+  aVault: PublicKey
+  bVault: PublicKey
+  aVaultLpMint: PublicKey
+  bVaultLpMint: PublicKey
+  aVaultLp: PublicKey
+  bVaultLp: PublicKey
+  protocolTokenAFee: PublicKey
+  protocolTokenBFee: PublicKey
+  feeOwner: PublicKey
+  mintMetadata: PublicKey
+  metadataProgram: PublicKey
+  vaultProgram: PublicKey
+  meteoraProgram: PublicKey
+  payer: PublicKey
+  rent: PublicKey
+  systemProgram: PublicKey
+  tokenProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function migrateToMeteora(
+  accounts: MigrateToMeteoraAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.wsolMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.payerTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.payerWsolAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.payerLpToken, isSigner: false, isWritable: true },
+    { pubkey: accounts.meteoraPool, isSigner: false, isWritable: true },
+    { pubkey: accounts.lpMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.aVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.bVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.aVaultLpMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.bVaultLpMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.aVaultLp, isSigner: false, isWritable: true },
+    { pubkey: accounts.bVaultLp, isSigner: false, isWritable: true },
+    { pubkey: accounts.protocolTokenAFee, isSigner: false, isWritable: true },
+    { pubkey: accounts.protocolTokenBFee, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeOwner, isSigner: false, isWritable: false },
+    { pubkey: accounts.mintMetadata, isSigner: false, isWritable: true },
+    { pubkey: accounts.metadataProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.vaultProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.meteoraProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([161, 66, 43, 194, 98, 181, 112, 175])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
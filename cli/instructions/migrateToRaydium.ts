@@ -0,0 +1,74 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrateToRaydiumAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  solVault: PublicKey
+  mintTokenOne: PublicKey
+  poolTokenAccountOne: PublicKey
+  wsolMint: PublicKey
+  payerTokenAccount: PublicKey
+  payerWsolAccount: PublicKey
+  payerLpToken: PublicKey
+  ammConfig: PublicKey
+  raydiumAuthority: PublicKey
+  raydiumPoolState: PublicKey
+  lpMint: PublicKey
+  token0Vault: PublicKey
+  token1Vault: PublicKey
+  createPoolFee: PublicKey
+  observationState: PublicKey
+  raydiumProgram: PublicKey
+  payer: PublicKey
+  rent: PublicKey
+  systemProgram: PublicKey
+  tokenProgram: PublicKey
+  associatedTokenProgram: PublicKey
+}
+
+export function migrateToRaydium(
+  accounts: MigrateToRaydiumAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
+    { pubkey: accounts.wsolMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.payerTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.payerWsolAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.payerLpToken, isSigner: false, isWritable: true },
+    { pubkey: accounts.ammConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.raydiumAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.raydiumPoolState, isSigner: false, isWritable: true },
+    { pubkey: accounts.lpMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.token0Vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.token1Vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.createPoolFee, isSigner: false, isWritable: true },
+    { pubkey: accounts.observationState, isSigner: false, isWritable: true },
+    { pubkey: accounts.raydiumProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([116, 139, 75, 192, 86, 63, 121, 169])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,6 +1,7 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface RemoveLiquidityArgs {
@@ -8,7 +9,9 @@ export interface RemoveLiquidityArgs {
 }
 
 export interface RemoveLiquidityAccounts {
+  dexConfigurationAccount: PublicKey
   pool: PublicKey
+  solVault: PublicKey
   liquidityProviderAccount: PublicKey
   mintTokenOne: PublicKey
   poolTokenAccountOne: PublicKey
@@ -28,7 +31,13 @@ export function removeLiquidity(
   programId: PublicKey = PROGRAM_ID
 ) {
   const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
     { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
     {
       pubkey: accounts.liquidityProviderAccount,
       isSigner: false,
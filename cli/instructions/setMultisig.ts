@@ -0,0 +1,47 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetMultisigArgs {
+  signers: Array<PublicKey>
+  threshold: number
+}
+
+export interface SetMultisigAccounts {
+  dexConfigurationAccount: PublicKey
+  admin: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.vec(borsh.publicKey(), "signers"),
+  borsh.u8("threshold"),
+])
+
+export function setMultisig(
+  args: SetMultisigArgs,
+  accounts: SetMultisigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([251, 6, 245, 35, 115, 42, 77, 186])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      signers: args.signers,
+      threshold: args.threshold,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
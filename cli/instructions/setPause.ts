@@ -0,0 +1,42 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetPauseArgs {
+  pause: types.PauseStateKind
+}
+
+export interface SetPauseAccounts {
+  dexConfigurationAccount: PublicKey
+  admin: PublicKey
+}
+
+export const layout = borsh.struct([types.PauseState.layout("pause")])
+
+export function setPause(
+  args: SetPauseArgs,
+  accounts: SetPauseAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([63, 32, 154, 2, 56, 103, 79, 45])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      pause: args.pause.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
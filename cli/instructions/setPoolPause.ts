@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetPoolPauseArgs {
+  pause: types.PauseStateKind
+}
+
+export interface SetPoolPauseAccounts {
+  dexConfigurationAccount: PublicKey
+  pool: PublicKey
+  mintTokenOne: PublicKey
+  admin: PublicKey
+}
+
+export const layout = borsh.struct([types.PauseState.layout("pause")])
+
+export function setPoolPause(
+  args: SetPoolPauseArgs,
+  accounts: SetPoolPauseAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: false },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([52, 171, 212, 208, 28, 209, 90, 15])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      pause: args.pause.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,16 +1,20 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface SwapArgs {
   amount: BN
-  style: BN
+  direction: types.TradeDirectionKind
+  minAmountOut: BN
 }
 
 export interface SwapAccounts {
   dexConfigurationAccount: PublicKey
   pool: PublicKey
+  solVault: PublicKey
+  treasury: PublicKey
   mintTokenOne: PublicKey
   poolTokenAccountOne: PublicKey
   userTokenAccountOne: PublicKey
@@ -21,7 +25,11 @@ export interface SwapAccounts {
   associatedTokenProgram: PublicKey
 }
 
-export const layout = borsh.struct([borsh.u64("amount"), borsh.u64("style")])
+export const layout = borsh.struct([
+  borsh.u64("amount"),
+  types.TradeDirection.layout("direction"),
+  borsh.u64("minAmountOut"),
+])
 
 export function swap(
   args: SwapArgs,
@@ -32,9 +40,11 @@ export function swap(
     {
       pubkey: accounts.dexConfigurationAccount,
       isSigner: false,
-      isWritable: true,
+      isWritable: false,
     },
     { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.treasury, isSigner: false, isWritable: true },
     { pubkey: accounts.mintTokenOne, isSigner: false, isWritable: true },
     { pubkey: accounts.poolTokenAccountOne, isSigner: false, isWritable: true },
     { pubkey: accounts.userTokenAccountOne, isSigner: false, isWritable: true },
@@ -53,7 +63,8 @@ export function swap(
   const len = layout.encode(
     {
       amount: args.amount,
-      style: args.style,
+      direction: args.direction.toEncodable(),
+      minAmountOut: args.minAmountOut,
     },
     buffer
   )
@@ -0,0 +1,66 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UpdateConfigArgs {
+  buyFeeBps: number
+  sellFeeBps: number
+  initialVirtualSolReserves: BN
+  initialVirtualTokenReserves: BN
+  completionThreshold: types.CompletionThresholdKind
+  treasury: PublicKey
+  raydiumAmmConfig: PublicKey
+}
+
+export interface UpdateConfigAccounts {
+  dexConfigurationAccount: PublicKey
+  pendingUpdate: PublicKey
+  admin: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u16("buyFeeBps"),
+  borsh.u16("sellFeeBps"),
+  borsh.u64("initialVirtualSolReserves"),
+  borsh.u64("initialVirtualTokenReserves"),
+  types.CompletionThreshold.layout("completionThreshold"),
+  borsh.publicKey("treasury"),
+  borsh.publicKey("raydiumAmmConfig"),
+])
+
+export function updateConfig(
+  args: UpdateConfigArgs,
+  accounts: UpdateConfigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    {
+      pubkey: accounts.dexConfigurationAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.pendingUpdate, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([29, 158, 252, 191, 10, 83, 219, 99])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      buyFeeBps: args.buyFeeBps,
+      sellFeeBps: args.sellFeeBps,
+      initialVirtualSolReserves: args.initialVirtualSolReserves,
+      initialVirtualTokenReserves: args.initialVirtualTokenReserves,
+      completionThreshold: args.completionThreshold.toEncodable(),
+      treasury: args.treasury,
+      raydiumAmmConfig: args.raydiumAmmConfig,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,422 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export type UpdateConfigFields = {
+  update: types.ConfigUpdateFields
+}
+export type UpdateConfigValue = {
+  update: types.ConfigUpdate
+}
+
+export interface UpdateConfigJSON {
+  kind: "UpdateConfig"
+  value: {
+    update: types.ConfigUpdateJSON
+  }
+}
+
+export class UpdateConfig {
+  static readonly discriminator = 0
+  static readonly kind = "UpdateConfig"
+  readonly discriminator = 0
+  readonly kind = "UpdateConfig"
+  readonly value: UpdateConfigValue
+
+  constructor(value: UpdateConfigFields) {
+    this.value = {
+      update: new types.ConfigUpdate({ ...value.update }),
+    }
+  }
+
+  toJSON(): UpdateConfigJSON {
+    return {
+      kind: "UpdateConfig",
+      value: {
+        update: this.value.update.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      UpdateConfig: {
+        update: types.ConfigUpdate.toEncodable(this.value.update),
+      },
+    }
+  }
+}
+
+export interface CancelConfigUpdateJSON {
+  kind: "CancelConfigUpdate"
+}
+
+export class CancelConfigUpdate {
+  static readonly discriminator = 1
+  static readonly kind = "CancelConfigUpdate"
+  readonly discriminator = 1
+  readonly kind = "CancelConfigUpdate"
+
+  toJSON(): CancelConfigUpdateJSON {
+    return {
+      kind: "CancelConfigUpdate",
+    }
+  }
+
+  toEncodable() {
+    return {
+      CancelConfigUpdate: {},
+    }
+  }
+}
+
+export type SetPauseFields = {
+  pause: types.PauseStateKind
+}
+export type SetPauseValue = {
+  pause: types.PauseStateKind
+}
+
+export interface SetPauseJSON {
+  kind: "SetPause"
+  value: {
+    pause: types.PauseStateJSON
+  }
+}
+
+export class SetPause {
+  static readonly discriminator = 2
+  static readonly kind = "SetPause"
+  readonly discriminator = 2
+  readonly kind = "SetPause"
+  readonly value: SetPauseValue
+
+  constructor(value: SetPauseFields) {
+    this.value = {
+      pause: value.pause,
+    }
+  }
+
+  toJSON(): SetPauseJSON {
+    return {
+      kind: "SetPause",
+      value: {
+        pause: this.value.pause.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SetPause: {
+        pause: this.value.pause.toEncodable(),
+      },
+    }
+  }
+}
+
+export type SetPoolPauseFields = {
+  pool: PublicKey
+  pause: types.PauseStateKind
+}
+export type SetPoolPauseValue = {
+  pool: PublicKey
+  pause: types.PauseStateKind
+}
+
+export interface SetPoolPauseJSON {
+  kind: "SetPoolPause"
+  value: {
+    pool: string
+    pause: types.PauseStateJSON
+  }
+}
+
+export class SetPoolPause {
+  static readonly discriminator = 3
+  static readonly kind = "SetPoolPause"
+  readonly discriminator = 3
+  readonly kind = "SetPoolPause"
+  readonly value: SetPoolPauseValue
+
+  constructor(value: SetPoolPauseFields) {
+    this.value = {
+      pool: value.pool,
+      pause: value.pause,
+    }
+  }
+
+  toJSON(): SetPoolPauseJSON {
+    return {
+      kind: "SetPoolPause",
+      value: {
+        pool: this.value.pool.toString(),
+        pause: this.value.pause.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SetPoolPause: {
+        pool: this.value.pool,
+        pause: this.value.pause.toEncodable(),
+      },
+    }
+  }
+}
+
+export type ReopenPoolFields = {
+  pool: PublicKey
+}
+export type ReopenPoolValue = {
+  pool: PublicKey
+}
+
+export interface ReopenPoolJSON {
+  kind: "ReopenPool"
+  value: {
+    pool: string
+  }
+}
+
+export class ReopenPool {
+  static readonly discriminator = 4
+  static readonly kind = "ReopenPool"
+  readonly discriminator = 4
+  readonly kind = "ReopenPool"
+  readonly value: ReopenPoolValue
+
+  constructor(value: ReopenPoolFields) {
+    this.value = {
+      pool: value.pool,
+    }
+  }
+
+  toJSON(): ReopenPoolJSON {
+    return {
+      kind: "ReopenPool",
+      value: {
+        pool: this.value.pool.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      ReopenPool: {
+        pool: this.value.pool,
+      },
+    }
+  }
+}
+
+export type SetMultisigFields = {
+  signers: Array<PublicKey>
+  threshold: number
+}
+export type SetMultisigValue = {
+  signers: Array<PublicKey>
+  threshold: number
+}
+
+export interface SetMultisigJSON {
+  kind: "SetMultisig"
+  value: {
+    signers: Array<string>
+    threshold: number
+  }
+}
+
+export class SetMultisig {
+  static readonly discriminator = 5
+  static readonly kind = "SetMultisig"
+  readonly discriminator = 5
+  readonly kind = "SetMultisig"
+  readonly value: SetMultisigValue
+
+  constructor(value: SetMultisigFields) {
+    this.value = {
+      signers: value.signers,
+      threshold: value.threshold,
+    }
+  }
+
+  toJSON(): SetMultisigJSON {
+    return {
+      kind: "SetMultisig",
+      value: {
+        signers: this.value.signers.map((item) => item.toString()),
+        threshold: this.value.threshold,
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SetMultisig: {
+        signers: this.value.signers,
+        threshold: this.value.threshold,
+      },
+    }
+  }
+}
+
+export type SetAdminFields = {
+  admin: PublicKey
+}
+export type SetAdminValue = {
+  admin: PublicKey
+}
+
+export interface SetAdminJSON {
+  kind: "SetAdmin"
+  value: {
+    admin: string
+  }
+}
+
+export class SetAdmin {
+  static readonly discriminator = 6
+  static readonly kind = "SetAdmin"
+  readonly discriminator = 6
+  readonly kind = "SetAdmin"
+  readonly value: SetAdminValue
+
+  constructor(value: SetAdminFields) {
+    this.value = {
+      admin: value.admin,
+    }
+  }
+
+  toJSON(): SetAdminJSON {
+    return {
+      kind: "SetAdmin",
+      value: {
+        admin: this.value.admin.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SetAdmin: {
+        admin: this.value.admin,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.AdminActionKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("UpdateConfig" in obj) {
+    const val = obj["UpdateConfig"]
+    return new UpdateConfig({
+      update: types.ConfigUpdate.fromDecoded(val["update"]),
+    })
+  }
+  if ("CancelConfigUpdate" in obj) {
+    return new CancelConfigUpdate()
+  }
+  if ("SetPause" in obj) {
+    const val = obj["SetPause"]
+    return new SetPause({
+      pause: types.PauseState.fromDecoded(val["pause"]),
+    })
+  }
+  if ("SetPoolPause" in obj) {
+    const val = obj["SetPoolPause"]
+    return new SetPoolPause({
+      pool: val["pool"],
+      pause: types.PauseState.fromDecoded(val["pause"]),
+    })
+  }
+  if ("ReopenPool" in obj) {
+    const val = obj["ReopenPool"]
+    return new ReopenPool({
+      pool: val["pool"],
+    })
+  }
+  if ("SetMultisig" in obj) {
+    const val = obj["SetMultisig"]
+    return new SetMultisig({
+      signers: val["signers"],
+      threshold: val["threshold"],
+    })
+  }
+  if ("SetAdmin" in obj) {
+    const val = obj["SetAdmin"]
+    return new SetAdmin({
+      admin: val["admin"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.AdminActionJSON): types.AdminActionKind {
+  switch (obj.kind) {
+    case "UpdateConfig": {
+      return new UpdateConfig({
+        update: types.ConfigUpdate.fromJSON(obj.value.update),
+      })
+    }
+    case "CancelConfigUpdate": {
+      return new CancelConfigUpdate()
+    }
+    case "SetPause": {
+      return new SetPause({
+        pause: types.PauseState.fromJSON(obj.value.pause),
+      })
+    }
+    case "SetPoolPause": {
+      return new SetPoolPause({
+        pool: new PublicKey(obj.value.pool),
+        pause: types.PauseState.fromJSON(obj.value.pause),
+      })
+    }
+    case "ReopenPool": {
+      return new ReopenPool({
+        pool: new PublicKey(obj.value.pool),
+      })
+    }
+    case "SetMultisig": {
+      return new SetMultisig({
+        signers: obj.value.signers.map((item) => new PublicKey(item)),
+        threshold: obj.value.threshold,
+      })
+    }
+    case "SetAdmin": {
+      return new SetAdmin({
+        admin: new PublicKey(obj.value.admin),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([types.ConfigUpdate.layout("update")], "UpdateConfig"),
+    borsh.struct([], "CancelConfigUpdate"),
+    borsh.struct([types.PauseState.layout("pause")], "SetPause"),
+    borsh.struct([
+      borsh.publicKey("pool"),
+      types.PauseState.layout("pause"),
+    ], "SetPoolPause"),
+    borsh.struct([borsh.publicKey("pool")], "ReopenPool"),
+    borsh.struct([
+      borsh.vec(borsh.publicKey(), "signers"),
+      borsh.u8("threshold"),
+    ], "SetMultisig"),
+    borsh.struct([borsh.publicKey("admin")], "SetAdmin"),
+  ])
+  if (property !== undefined) {
+    return ret.replace(property)
+  }
+  return ret
+}
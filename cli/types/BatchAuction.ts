@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface BatchAuctionFields {
+  endSlot: BN
+  totalOrders: BN
+  tokensCleared: BN
+  acceptedOrders: BN
+  ordersClaimed: BN
+  tokensClaimed: BN
+  refundsPaid: BN
+}
+
+export interface BatchAuctionJSON {
+  endSlot: string
+  totalOrders: string
+  tokensCleared: string
+  acceptedOrders: string
+  ordersClaimed: string
+  tokensClaimed: string
+  refundsPaid: string
+}
+
+export class BatchAuction {
+  readonly endSlot: BN
+  readonly totalOrders: BN
+  readonly tokensCleared: BN
+  readonly acceptedOrders: BN
+  readonly ordersClaimed: BN
+  readonly tokensClaimed: BN
+  readonly refundsPaid: BN
+
+  constructor(fields: BatchAuctionFields) {
+    this.endSlot = fields.endSlot
+    this.totalOrders = fields.totalOrders
+    this.tokensCleared = fields.tokensCleared
+    this.acceptedOrders = fields.acceptedOrders
+    this.ordersClaimed = fields.ordersClaimed
+    this.tokensClaimed = fields.tokensClaimed
+    this.refundsPaid = fields.refundsPaid
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("endSlot"),
+        borsh.u64("totalOrders"),
+        borsh.u64("tokensCleared"),
+        borsh.u64("acceptedOrders"),
+        borsh.u64("ordersClaimed"),
+        borsh.u64("tokensClaimed"),
+        borsh.u64("refundsPaid"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new BatchAuction({
+      endSlot: obj.endSlot,
+      totalOrders: obj.totalOrders,
+      tokensCleared: obj.tokensCleared,
+      acceptedOrders: obj.acceptedOrders,
+      ordersClaimed: obj.ordersClaimed,
+      tokensClaimed: obj.tokensClaimed,
+      refundsPaid: obj.refundsPaid,
+    })
+  }
+
+  static toEncodable(fields: BatchAuctionFields) {
+    return {
+      endSlot: fields.endSlot,
+      totalOrders: fields.totalOrders,
+      tokensCleared: fields.tokensCleared,
+      acceptedOrders: fields.acceptedOrders,
+      ordersClaimed: fields.ordersClaimed,
+      tokensClaimed: fields.tokensClaimed,
+      refundsPaid: fields.refundsPaid,
+    }
+  }
+
+  toJSON(): BatchAuctionJSON {
+    return {
+      endSlot: this.endSlot.toString(),
+      totalOrders: this.totalOrders.toString(),
+      tokensCleared: this.tokensCleared.toString(),
+      acceptedOrders: this.acceptedOrders.toString(),
+      ordersClaimed: this.ordersClaimed.toString(),
+      tokensClaimed: this.tokensClaimed.toString(),
+      refundsPaid: this.refundsPaid.toString(),
+    }
+  }
+
+  static fromJSON(obj: BatchAuctionJSON): BatchAuction {
+    return new BatchAuction({
+      endSlot: new BN(obj.endSlot),
+      totalOrders: new BN(obj.totalOrders),
+      tokensCleared: new BN(obj.tokensCleared),
+      acceptedOrders: new BN(obj.acceptedOrders),
+      ordersClaimed: new BN(obj.ordersClaimed),
+      tokensClaimed: new BN(obj.tokensClaimed),
+      refundsPaid: new BN(obj.refundsPaid),
+    })
+  }
+
+  toEncodable() {
+    return BatchAuction.toEncodable(this)
+  }
+}
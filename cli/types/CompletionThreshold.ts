@@ -0,0 +1,174 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DisabledJSON {
+  kind: "Disabled"
+}
+
+export class Disabled {
+  static readonly discriminator = 0
+  static readonly kind = "Disabled"
+  readonly discriminator = 0
+  readonly kind = "Disabled"
+
+  toJSON(): DisabledJSON {
+    return {
+      kind: "Disabled",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Disabled: {},
+    }
+  }
+}
+
+export type SolRaisedFields = {
+  lamports: BN
+}
+export type SolRaisedValue = {
+  lamports: BN
+}
+
+export interface SolRaisedJSON {
+  kind: "SolRaised"
+  value: {
+    lamports: string
+  }
+}
+
+export class SolRaised {
+  static readonly discriminator = 1
+  static readonly kind = "SolRaised"
+  readonly discriminator = 1
+  readonly kind = "SolRaised"
+  readonly value: SolRaisedValue
+
+  constructor(value: SolRaisedFields) {
+    this.value = {
+      lamports: value.lamports,
+    }
+  }
+
+  toJSON(): SolRaisedJSON {
+    return {
+      kind: "SolRaised",
+      value: {
+        lamports: this.value.lamports.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SolRaised: {
+        lamports: this.value.lamports,
+      },
+    }
+  }
+}
+
+export type TokensSoldFields = {
+  amount: BN
+}
+export type TokensSoldValue = {
+  amount: BN
+}
+
+export interface TokensSoldJSON {
+  kind: "TokensSold"
+  value: {
+    amount: string
+  }
+}
+
+export class TokensSold {
+  static readonly discriminator = 2
+  static readonly kind = "TokensSold"
+  readonly discriminator = 2
+  readonly kind = "TokensSold"
+  readonly value: TokensSoldValue
+
+  constructor(value: TokensSoldFields) {
+    this.value = {
+      amount: value.amount,
+    }
+  }
+
+  toJSON(): TokensSoldJSON {
+    return {
+      kind: "TokensSold",
+      value: {
+        amount: this.value.amount.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      TokensSold: {
+        amount: this.value.amount,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.CompletionThresholdKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Disabled" in obj) {
+    return new Disabled()
+  }
+  if ("SolRaised" in obj) {
+    const val = obj["SolRaised"]
+    return new SolRaised({
+      lamports: val["lamports"],
+    })
+  }
+  if ("TokensSold" in obj) {
+    const val = obj["TokensSold"]
+    return new TokensSold({
+      amount: val["amount"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.CompletionThresholdJSON
+): types.CompletionThresholdKind {
+  switch (obj.kind) {
+    case "Disabled": {
+      return new Disabled()
+    }
+    case "SolRaised": {
+      return new SolRaised({
+        lamports: new BN(obj.value.lamports),
+      })
+    }
+    case "TokensSold": {
+      return new TokensSold({
+        amount: new BN(obj.value.amount),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Disabled"),
+    borsh.struct([borsh.u64("lamports")], "SolRaised"),
+    borsh.struct([borsh.u64("amount")], "TokensSold"),
+  ])
+  if (property !== undefined) {
+    return ret.replace(property)
+  }
+  return ret
+}
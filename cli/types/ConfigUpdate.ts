@@ -0,0 +1,114 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ConfigUpdateFields {
+  buyFeeBps: number
+  sellFeeBps: number
+  initialVirtualSolReserves: BN
+  initialVirtualTokenReserves: BN
+  completionThreshold: types.CompletionThresholdKind
+  treasury: PublicKey
+  raydiumAmmConfig: PublicKey
+}
+
+export interface ConfigUpdateJSON {
+  buyFeeBps: number
+  sellFeeBps: number
+  initialVirtualSolReserves: string
+  initialVirtualTokenReserves: string
+  completionThreshold: types.CompletionThresholdJSON
+  treasury: string
+  raydiumAmmConfig: string
+}
+
+export class ConfigUpdate {
+  readonly buyFeeBps: number
+  readonly sellFeeBps: number
+  readonly initialVirtualSolReserves: BN
+  readonly initialVirtualTokenReserves: BN
+  readonly completionThreshold: types.CompletionThresholdKind
+  readonly treasury: PublicKey
+  readonly raydiumAmmConfig: PublicKey
+
+  constructor(fields: ConfigUpdateFields) {
+    this.buyFeeBps = fields.buyFeeBps
+    this.sellFeeBps = fields.sellFeeBps
+    this.initialVirtualSolReserves = fields.initialVirtualSolReserves
+    this.initialVirtualTokenReserves = fields.initialVirtualTokenReserves
+    this.completionThreshold = fields.completionThreshold
+    this.treasury = fields.treasury
+    this.raydiumAmmConfig = fields.raydiumAmmConfig
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u16("buyFeeBps"),
+        borsh.u16("sellFeeBps"),
+        borsh.u64("initialVirtualSolReserves"),
+        borsh.u64("initialVirtualTokenReserves"),
+        types.CompletionThreshold.layout("completionThreshold"),
+        borsh.publicKey("treasury"),
+        borsh.publicKey("raydiumAmmConfig"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ConfigUpdate({
+      buyFeeBps: obj.buyFeeBps,
+      sellFeeBps: obj.sellFeeBps,
+      initialVirtualSolReserves: obj.initialVirtualSolReserves,
+      initialVirtualTokenReserves: obj.initialVirtualTokenReserves,
+      completionThreshold:
+        types.CompletionThreshold.fromDecoded(obj.completionThreshold),
+      treasury: obj.treasury,
+      raydiumAmmConfig: obj.raydiumAmmConfig,
+    })
+  }
+
+  static toEncodable(fields: ConfigUpdateFields) {
+    return {
+      buyFeeBps: fields.buyFeeBps,
+      sellFeeBps: fields.sellFeeBps,
+      initialVirtualSolReserves: fields.initialVirtualSolReserves,
+      initialVirtualTokenReserves: fields.initialVirtualTokenReserves,
+      completionThreshold: fields.completionThreshold.toEncodable(),
+      treasury: fields.treasury,
+      raydiumAmmConfig: fields.raydiumAmmConfig,
+    }
+  }
+
+  toJSON(): ConfigUpdateJSON {
+    return {
+      buyFeeBps: this.buyFeeBps,
+      sellFeeBps: this.sellFeeBps,
+      initialVirtualSolReserves: this.initialVirtualSolReserves.toString(),
+      initialVirtualTokenReserves: this.initialVirtualTokenReserves.toString(),
+      completionThreshold: this.completionThreshold.toJSON(),
+      treasury: this.treasury.toString(),
+      raydiumAmmConfig: this.raydiumAmmConfig.toString(),
+    }
+  }
+
+  static fromJSON(obj: ConfigUpdateJSON): ConfigUpdate {
+    return new ConfigUpdate({
+      buyFeeBps: obj.buyFeeBps,
+      sellFeeBps: obj.sellFeeBps,
+      initialVirtualSolReserves: new BN(obj.initialVirtualSolReserves),
+      initialVirtualTokenReserves: new BN(obj.initialVirtualTokenReserves),
+      completionThreshold:
+        types.CompletionThreshold.fromJSON(obj.completionThreshold),
+      treasury: new PublicKey(obj.treasury),
+      raydiumAmmConfig: new PublicKey(obj.raydiumAmmConfig),
+    })
+  }
+
+  toEncodable() {
+    return ConfigUpdate.toEncodable(this)
+  }
+}
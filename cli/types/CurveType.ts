@@ -0,0 +1,148 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ConstantProductJSON {
+  kind: "ConstantProduct"
+}
+
+export class ConstantProduct {
+  static readonly discriminator = 0
+  static readonly kind = "ConstantProduct"
+  readonly discriminator = 0
+  readonly kind = "ConstantProduct"
+
+  toJSON(): ConstantProductJSON {
+    return {
+      kind: "ConstantProduct",
+    }
+  }
+
+  toEncodable() {
+    return {
+      ConstantProduct: {},
+    }
+  }
+}
+
+export interface LinearJSON {
+  kind: "Linear"
+}
+
+export class Linear {
+  static readonly discriminator = 1
+  static readonly kind = "Linear"
+  readonly discriminator = 1
+  readonly kind = "Linear"
+
+  toJSON(): LinearJSON {
+    return {
+      kind: "Linear",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Linear: {},
+    }
+  }
+}
+
+export interface ExponentialJSON {
+  kind: "Exponential"
+}
+
+export class Exponential {
+  static readonly discriminator = 2
+  static readonly kind = "Exponential"
+  readonly discriminator = 2
+  readonly kind = "Exponential"
+
+  toJSON(): ExponentialJSON {
+    return {
+      kind: "Exponential",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Exponential: {},
+    }
+  }
+}
+
+export interface SigmoidJSON {
+  kind: "Sigmoid"
+}
+
+export class Sigmoid {
+  static readonly discriminator = 3
+  static readonly kind = "Sigmoid"
+  readonly discriminator = 3
+  readonly kind = "Sigmoid"
+
+  toJSON(): SigmoidJSON {
+    return {
+      kind: "Sigmoid",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Sigmoid: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.CurveTypeKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("ConstantProduct" in obj) {
+    return new ConstantProduct()
+  }
+  if ("Linear" in obj) {
+    return new Linear()
+  }
+  if ("Exponential" in obj) {
+    return new Exponential()
+  }
+  if ("Sigmoid" in obj) {
+    return new Sigmoid()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.CurveTypeJSON): types.CurveTypeKind {
+  switch (obj.kind) {
+    case "ConstantProduct": {
+      return new ConstantProduct()
+    }
+    case "Linear": {
+      return new Linear()
+    }
+    case "Exponential": {
+      return new Exponential()
+    }
+    case "Sigmoid": {
+      return new Sigmoid()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "ConstantProduct"),
+    borsh.struct([], "Linear"),
+    borsh.struct([], "Exponential"),
+    borsh.struct([], "Sigmoid"),
+  ])
+  if (property !== undefined) {
+    return ret.replace(property)
+  }
+  return ret
+}
@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DutchAuctionFields {
+  startSlot: BN
+  endSlot: BN
+  startPrice: BN
+  floorPrice: BN
+  tranche: BN
+  tokensSold: BN
+  solRaised: BN
+}
+
+export interface DutchAuctionJSON {
+  startSlot: string
+  endSlot: string
+  startPrice: string
+  floorPrice: string
+  tranche: string
+  tokensSold: string
+  solRaised: string
+}
+
+export class DutchAuction {
+  readonly startSlot: BN
+  readonly endSlot: BN
+  readonly startPrice: BN
+  readonly floorPrice: BN
+  readonly tranche: BN
+  readonly tokensSold: BN
+  readonly solRaised: BN
+
+  constructor(fields: DutchAuctionFields) {
+    this.startSlot = fields.startSlot
+    this.endSlot = fields.endSlot
+    this.startPrice = fields.startPrice
+    this.floorPrice = fields.floorPrice
+    this.tranche = fields.tranche
+    this.tokensSold = fields.tokensSold
+    this.solRaised = fields.solRaised
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("startSlot"),
+        borsh.u64("endSlot"),
+        borsh.u64("startPrice"),
+        borsh.u64("floorPrice"),
+        borsh.u64("tranche"),
+        borsh.u64("tokensSold"),
+        borsh.u64("solRaised"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DutchAuction({
+      startSlot: obj.startSlot,
+      endSlot: obj.endSlot,
+      startPrice: obj.startPrice,
+      floorPrice: obj.floorPrice,
+      tranche: obj.tranche,
+      tokensSold: obj.tokensSold,
+      solRaised: obj.solRaised,
+    })
+  }
+
+  static toEncodable(fields: DutchAuctionFields) {
+    return {
+      startSlot: fields.startSlot,
+      endSlot: fields.endSlot,
+      startPrice: fields.startPrice,
+      floorPrice: fields.floorPrice,
+      tranche: fields.tranche,
+      tokensSold: fields.tokensSold,
+      solRaised: fields.solRaised,
+    }
+  }
+
+  toJSON(): DutchAuctionJSON {
+    return {
+      startSlot: this.startSlot.toString(),
+      endSlot: this.endSlot.toString(),
+      startPrice: this.startPrice.toString(),
+      floorPrice: this.floorPrice.toString(),
+      tranche: this.tranche.toString(),
+      tokensSold: this.tokensSold.toString(),
+      solRaised: this.solRaised.toString(),
+    }
+  }
+
+  static fromJSON(obj: DutchAuctionJSON): DutchAuction {
+    return new DutchAuction({
+      startSlot: new BN(obj.startSlot),
+      endSlot: new BN(obj.endSlot),
+      startPrice: new BN(obj.startPrice),
+      floorPrice: new BN(obj.floorPrice),
+      tranche: new BN(obj.tranche),
+      tokensSold: new BN(obj.tokensSold),
+      solRaised: new BN(obj.solRaised),
+    })
+  }
+
+  toEncodable() {
+    return DutchAuction.toEncodable(this)
+  }
+}
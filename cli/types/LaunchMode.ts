@@ -0,0 +1,305 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface InstantJSON {
+  kind: "Instant"
+}
+
+export class Instant {
+  static readonly discriminator = 0
+  static readonly kind = "Instant"
+  readonly discriminator = 0
+  readonly kind = "Instant"
+
+  toJSON(): InstantJSON {
+    return {
+      kind: "Instant",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Instant: {},
+    }
+  }
+}
+
+export type PresaleFields = {
+  startSlot: BN
+  endSlot: BN
+  softCap: BN
+  hardCap: BN
+  allowlistRoot: Array<number> | null
+}
+export type PresaleValue = {
+  startSlot: BN
+  endSlot: BN
+  softCap: BN
+  hardCap: BN
+  allowlistRoot: Array<number> | null
+}
+
+export interface PresaleJSON {
+  kind: "Presale"
+  value: {
+    startSlot: string
+    endSlot: string
+    softCap: string
+    hardCap: string
+    allowlistRoot: Array<number> | null
+  }
+}
+
+export class Presale {
+  static readonly discriminator = 1
+  static readonly kind = "Presale"
+  readonly discriminator = 1
+  readonly kind = "Presale"
+  readonly value: PresaleValue
+
+  constructor(value: PresaleFields) {
+    this.value = {
+      startSlot: value.startSlot,
+      endSlot: value.endSlot,
+      softCap: value.softCap,
+      hardCap: value.hardCap,
+      allowlistRoot: value.allowlistRoot,
+    }
+  }
+
+  toJSON(): PresaleJSON {
+    return {
+      kind: "Presale",
+      value: {
+        startSlot: this.value.startSlot.toString(),
+        endSlot: this.value.endSlot.toString(),
+        softCap: this.value.softCap.toString(),
+        hardCap: this.value.hardCap.toString(),
+        allowlistRoot: this.value.allowlistRoot,
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Presale: {
+        startSlot: this.value.startSlot,
+        endSlot: this.value.endSlot,
+        softCap: this.value.softCap,
+        hardCap: this.value.hardCap,
+        allowlistRoot: this.value.allowlistRoot,
+      },
+    }
+  }
+}
+
+export type DutchAuctionFields = {
+  startSlot: BN
+  endSlot: BN
+  startPrice: BN
+  floorPrice: BN
+  tranche: BN
+}
+export type DutchAuctionValue = {
+  startSlot: BN
+  endSlot: BN
+  startPrice: BN
+  floorPrice: BN
+  tranche: BN
+}
+
+export interface DutchAuctionJSON {
+  kind: "DutchAuction"
+  value: {
+    startSlot: string
+    endSlot: string
+    startPrice: string
+    floorPrice: string
+    tranche: string
+  }
+}
+
+export class DutchAuction {
+  static readonly discriminator = 2
+  static readonly kind = "DutchAuction"
+  readonly discriminator = 2
+  readonly kind = "DutchAuction"
+  readonly value: DutchAuctionValue
+
+  constructor(value: DutchAuctionFields) {
+    this.value = {
+      startSlot: value.startSlot,
+      endSlot: value.endSlot,
+      startPrice: value.startPrice,
+      floorPrice: value.floorPrice,
+      tranche: value.tranche,
+    }
+  }
+
+  toJSON(): DutchAuctionJSON {
+    return {
+      kind: "DutchAuction",
+      value: {
+        startSlot: this.value.startSlot.toString(),
+        endSlot: this.value.endSlot.toString(),
+        startPrice: this.value.startPrice.toString(),
+        floorPrice: this.value.floorPrice.toString(),
+        tranche: this.value.tranche.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      DutchAuction: {
+        startSlot: this.value.startSlot,
+        endSlot: this.value.endSlot,
+        startPrice: this.value.startPrice,
+        floorPrice: this.value.floorPrice,
+        tranche: this.value.tranche,
+      },
+    }
+  }
+}
+
+export type BatchAuctionFields = {
+  slots: BN
+}
+export type BatchAuctionValue = {
+  slots: BN
+}
+
+export interface BatchAuctionJSON {
+  kind: "BatchAuction"
+  value: {
+    slots: string
+  }
+}
+
+export class BatchAuction {
+  static readonly discriminator = 3
+  static readonly kind = "BatchAuction"
+  readonly discriminator = 3
+  readonly kind = "BatchAuction"
+  readonly value: BatchAuctionValue
+
+  constructor(value: BatchAuctionFields) {
+    this.value = {
+      slots: value.slots,
+    }
+  }
+
+  toJSON(): BatchAuctionJSON {
+    return {
+      kind: "BatchAuction",
+      value: {
+        slots: this.value.slots.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      BatchAuction: {
+        slots: this.value.slots,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.LaunchModeKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Instant" in obj) {
+    return new Instant()
+  }
+  if ("Presale" in obj) {
+    const val = obj["Presale"]
+    return new Presale({
+      startSlot: val["start_slot"],
+      endSlot: val["end_slot"],
+      softCap: val["soft_cap"],
+      hardCap: val["hard_cap"],
+      allowlistRoot: val["allowlist_root"],
+    })
+  }
+  if ("DutchAuction" in obj) {
+    const val = obj["DutchAuction"]
+    return new DutchAuction({
+      startSlot: val["start_slot"],
+      endSlot: val["end_slot"],
+      startPrice: val["start_price"],
+      floorPrice: val["floor_price"],
+      tranche: val["tranche"],
+    })
+  }
+  if ("BatchAuction" in obj) {
+    const val = obj["BatchAuction"]
+    return new BatchAuction({
+      slots: val["slots"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.LaunchModeJSON): types.LaunchModeKind {
+  switch (obj.kind) {
+    case "Instant": {
+      return new Instant()
+    }
+    case "Presale": {
+      return new Presale({
+        startSlot: new BN(obj.value.startSlot),
+        endSlot: new BN(obj.value.endSlot),
+        softCap: new BN(obj.value.softCap),
+        hardCap: new BN(obj.value.hardCap),
+        allowlistRoot: obj.value.allowlistRoot,
+      })
+    }
+    case "DutchAuction": {
+      return new DutchAuction({
+        startSlot: new BN(obj.value.startSlot),
+        endSlot: new BN(obj.value.endSlot),
+        startPrice: new BN(obj.value.startPrice),
+        floorPrice: new BN(obj.value.floorPrice),
+        tranche: new BN(obj.value.tranche),
+      })
+    }
+    case "BatchAuction": {
+      return new BatchAuction({
+        slots: new BN(obj.value.slots),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Instant"),
+    borsh.struct([
+      borsh.u64("start_slot"),
+      borsh.u64("end_slot"),
+      borsh.u64("soft_cap"),
+      borsh.u64("hard_cap"),
+      borsh.option(borsh.array(borsh.u8(), 32), "allowlist_root"),
+    ], "Presale"),
+    borsh.struct([
+      borsh.u64("start_slot"),
+      borsh.u64("end_slot"),
+      borsh.u64("start_price"),
+      borsh.u64("floor_price"),
+      borsh.u64("tranche"),
+    ], "DutchAuction"),
+    borsh.struct([borsh.u64("slots")], "BatchAuction"),
+  ])
+  if (property !== undefined) {
+    return ret.replace(property)
+  }
+  return ret
+}
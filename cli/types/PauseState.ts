@@ -0,0 +1,118 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UnpausedJSON {
+  kind: "Unpaused"
+}
+
+export class Unpaused {
+  static readonly discriminator = 0
+  static readonly kind = "Unpaused"
+  readonly discriminator = 0
+  readonly kind = "Unpaused"
+
+  toJSON(): UnpausedJSON {
+    return {
+      kind: "Unpaused",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Unpaused: {},
+    }
+  }
+}
+
+export interface PausedJSON {
+  kind: "Paused"
+}
+
+export class Paused {
+  static readonly discriminator = 1
+  static readonly kind = "Paused"
+  readonly discriminator = 1
+  readonly kind = "Paused"
+
+  toJSON(): PausedJSON {
+    return {
+      kind: "Paused",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Paused: {},
+    }
+  }
+}
+
+export interface WithdrawalsOnlyJSON {
+  kind: "WithdrawalsOnly"
+}
+
+export class WithdrawalsOnly {
+  static readonly discriminator = 2
+  static readonly kind = "WithdrawalsOnly"
+  readonly discriminator = 2
+  readonly kind = "WithdrawalsOnly"
+
+  toJSON(): WithdrawalsOnlyJSON {
+    return {
+      kind: "WithdrawalsOnly",
+    }
+  }
+
+  toEncodable() {
+    return {
+      WithdrawalsOnly: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.PauseStateKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Unpaused" in obj) {
+    return new Unpaused()
+  }
+  if ("Paused" in obj) {
+    return new Paused()
+  }
+  if ("WithdrawalsOnly" in obj) {
+    return new WithdrawalsOnly()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.PauseStateJSON): types.PauseStateKind {
+  switch (obj.kind) {
+    case "Unpaused": {
+      return new Unpaused()
+    }
+    case "Paused": {
+      return new Paused()
+    }
+    case "WithdrawalsOnly": {
+      return new WithdrawalsOnly()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Unpaused"),
+    borsh.struct([], "Paused"),
+    borsh.struct([], "WithdrawalsOnly"),
+  ])
+  if (property !== undefined) {
+    return ret.replace(property)
+  }
+  return ret
+}
@@ -0,0 +1,238 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ActiveJSON {
+  kind: "Active"
+}
+
+export class Active {
+  static readonly discriminator = 0
+  static readonly kind = "Active"
+  readonly discriminator = 0
+  readonly kind = "Active"
+
+  toJSON(): ActiveJSON {
+    return {
+      kind: "Active",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Active: {},
+    }
+  }
+}
+
+export interface CompletedJSON {
+  kind: "Completed"
+}
+
+export class Completed {
+  static readonly discriminator = 1
+  static readonly kind = "Completed"
+  readonly discriminator = 1
+  readonly kind = "Completed"
+
+  toJSON(): CompletedJSON {
+    return {
+      kind: "Completed",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Completed: {},
+    }
+  }
+}
+
+export interface MigratedJSON {
+  kind: "Migrated"
+}
+
+export class Migrated {
+  static readonly discriminator = 2
+  static readonly kind = "Migrated"
+  readonly discriminator = 2
+  readonly kind = "Migrated"
+
+  toJSON(): MigratedJSON {
+    return {
+      kind: "Migrated",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Migrated: {},
+    }
+  }
+}
+
+export interface PresaleJSON {
+  kind: "Presale"
+}
+
+export class Presale {
+  static readonly discriminator = 3
+  static readonly kind = "Presale"
+  readonly discriminator = 3
+  readonly kind = "Presale"
+
+  toJSON(): PresaleJSON {
+    return {
+      kind: "Presale",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Presale: {},
+    }
+  }
+}
+
+export interface CancelledJSON {
+  kind: "Cancelled"
+}
+
+export class Cancelled {
+  static readonly discriminator = 4
+  static readonly kind = "Cancelled"
+  readonly discriminator = 4
+  readonly kind = "Cancelled"
+
+  toJSON(): CancelledJSON {
+    return {
+      kind: "Cancelled",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Cancelled: {},
+    }
+  }
+}
+
+export interface AuctionJSON {
+  kind: "Auction"
+}
+
+export class Auction {
+  static readonly discriminator = 5
+  static readonly kind = "Auction"
+  readonly discriminator = 5
+  readonly kind = "Auction"
+
+  toJSON(): AuctionJSON {
+    return {
+      kind: "Auction",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Auction: {},
+    }
+  }
+}
+
+export interface BatchingJSON {
+  kind: "Batching"
+}
+
+export class Batching {
+  static readonly discriminator = 6
+  static readonly kind = "Batching"
+  readonly discriminator = 6
+  readonly kind = "Batching"
+
+  toJSON(): BatchingJSON {
+    return {
+      kind: "Batching",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Batching: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.PoolStatusKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Active" in obj) {
+    return new Active()
+  }
+  if ("Completed" in obj) {
+    return new Completed()
+  }
+  if ("Migrated" in obj) {
+    return new Migrated()
+  }
+  if ("Presale" in obj) {
+    return new Presale()
+  }
+  if ("Cancelled" in obj) {
+    return new Cancelled()
+  }
+  if ("Auction" in obj) {
+    return new Auction()
+  }
+  if ("Batching" in obj) {
+    return new Batching()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.PoolStatusJSON): types.PoolStatusKind {
+  switch (obj.kind) {
+    case "Active": {
+      return new Active()
+    }
+    case "Completed": {
+      return new Completed()
+    }
+    case "Migrated": {
+      return new Migrated()
+    }
+    case "Presale": {
+      return new Presale()
+    }
+    case "Cancelled": {
+      return new Cancelled()
+    }
+    case "Auction": {
+      return new Auction()
+    }
+    case "Batching": {
+      return new Batching()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Active"),
+    borsh.struct([], "Completed"),
+    borsh.struct([], "Migrated"),
+    borsh.struct([], "Presale"),
+    borsh.struct([], "Cancelled"),
+    borsh.struct([], "Auction"),
+    borsh.struct([], "Batching"),
+  ])
+  if (property !== undefined) {
+    return ret.replace(property)
+  }
+  return ret
+}
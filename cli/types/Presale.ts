@@ -0,0 +1,148 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PresaleFields {
+  startSlot: BN
+  endSlot: BN
+  softCap: BN
+  hardCap: BN
+  totalDeposits: BN
+  tokensAllocated: BN
+  acceptedDeposits: BN
+  depositsClaimed: BN
+  tokensClaimed: BN
+  refundsPaid: BN
+  allowlistRoot: Array<number> | null
+}
+
+export interface PresaleJSON {
+  startSlot: string
+  endSlot: string
+  softCap: string
+  hardCap: string
+  totalDeposits: string
+  tokensAllocated: string
+  acceptedDeposits: string
+  depositsClaimed: string
+  tokensClaimed: string
+  refundsPaid: string
+  allowlistRoot: Array<number> | null
+}
+
+export class Presale {
+  readonly startSlot: BN
+  readonly endSlot: BN
+  readonly softCap: BN
+  readonly hardCap: BN
+  readonly totalDeposits: BN
+  readonly tokensAllocated: BN
+  readonly acceptedDeposits: BN
+  readonly depositsClaimed: BN
+  readonly tokensClaimed: BN
+  readonly refundsPaid: BN
+  readonly allowlistRoot: Array<number> | null
+
+  constructor(fields: PresaleFields) {
+    this.startSlot = fields.startSlot
+    this.endSlot = fields.endSlot
+    this.softCap = fields.softCap
+    this.hardCap = fields.hardCap
+    this.totalDeposits = fields.totalDeposits
+    this.tokensAllocated = fields.tokensAllocated
+    this.acceptedDeposits = fields.acceptedDeposits
+    this.depositsClaimed = fields.depositsClaimed
+    this.tokensClaimed = fields.tokensClaimed
+    this.refundsPaid = fields.refundsPaid
+    this.allowlistRoot = fields.allowlistRoot
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("startSlot"),
+        borsh.u64("endSlot"),
+        borsh.u64("softCap"),
+        borsh.u64("hardCap"),
+        borsh.u64("totalDeposits"),
+        borsh.u64("tokensAllocated"),
+        borsh.u64("acceptedDeposits"),
+        borsh.u64("depositsClaimed"),
+        borsh.u64("tokensClaimed"),
+        borsh.u64("refundsPaid"),
+        borsh.option(borsh.array(borsh.u8(), 32), "allowlistRoot"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new Presale({
+      startSlot: obj.startSlot,
+      endSlot: obj.endSlot,
+      softCap: obj.softCap,
+      hardCap: obj.hardCap,
+      totalDeposits: obj.totalDeposits,
+      tokensAllocated: obj.tokensAllocated,
+      acceptedDeposits: obj.acceptedDeposits,
+      depositsClaimed: obj.depositsClaimed,
+      tokensClaimed: obj.tokensClaimed,
+      refundsPaid: obj.refundsPaid,
+      allowlistRoot: obj.allowlistRoot,
+    })
+  }
+
+  static toEncodable(fields: PresaleFields) {
+    return {
+      startSlot: fields.startSlot,
+      endSlot: fields.endSlot,
+      softCap: fields.softCap,
+      hardCap: fields.hardCap,
+      totalDeposits: fields.totalDeposits,
+      tokensAllocated: fields.tokensAllocated,
+      acceptedDeposits: fields.acceptedDeposits,
+      depositsClaimed: fields.depositsClaimed,
+      tokensClaimed: fields.tokensClaimed,
+      refundsPaid: fields.refundsPaid,
+      allowlistRoot: fields.allowlistRoot,
+    }
+  }
+
+  toJSON(): PresaleJSON {
+    return {
+      startSlot: this.startSlot.toString(),
+      endSlot: this.endSlot.toString(),
+      softCap: this.softCap.toString(),
+      hardCap: this.hardCap.toString(),
+      totalDeposits: this.totalDeposits.toString(),
+      tokensAllocated: this.tokensAllocated.toString(),
+      acceptedDeposits: this.acceptedDeposits.toString(),
+      depositsClaimed: this.depositsClaimed.toString(),
+      tokensClaimed: this.tokensClaimed.toString(),
+      refundsPaid: this.refundsPaid.toString(),
+      allowlistRoot: this.allowlistRoot,
+    }
+  }
+
+  static fromJSON(obj: PresaleJSON): Presale {
+    return new Presale({
+      startSlot: new BN(obj.startSlot),
+      endSlot: new BN(obj.endSlot),
+      softCap: new BN(obj.softCap),
+      hardCap: new BN(obj.hardCap),
+      totalDeposits: new BN(obj.totalDeposits),
+      tokensAllocated: new BN(obj.tokensAllocated),
+      acceptedDeposits: new BN(obj.acceptedDeposits),
+      depositsClaimed: new BN(obj.depositsClaimed),
+      tokensClaimed: new BN(obj.tokensClaimed),
+      refundsPaid: new BN(obj.refundsPaid),
+      allowlistRoot: obj.allowlistRoot,
+    })
+  }
+
+  toEncodable() {
+    return Presale.toEncodable(this)
+  }
+}
@@ -0,0 +1,118 @@
+import * as AdminAction from "./AdminAction"
+import * as CompletionThreshold from "./CompletionThreshold"
+import * as CurveType from "./CurveType"
+import * as LaunchMode from "./LaunchMode"
+import * as MigrationTarget from "./MigrationTarget"
+import * as PauseState from "./PauseState"
+import * as PoolStatus from "./PoolStatus"
+import * as TradeDirection from "./TradeDirection"
+
+export { AdminAction }
+
+export type AdminActionKind =
+  | AdminAction.UpdateConfig
+  | AdminAction.CancelConfigUpdate
+  | AdminAction.SetPause
+  | AdminAction.SetPoolPause
+  | AdminAction.ReopenPool
+  | AdminAction.SetMultisig
+  | AdminAction.SetAdmin
+export type AdminActionJSON =
+  | AdminAction.UpdateConfigJSON
+  | AdminAction.CancelConfigUpdateJSON
+  | AdminAction.SetPauseJSON
+  | AdminAction.SetPoolPauseJSON
+  | AdminAction.ReopenPoolJSON
+  | AdminAction.SetMultisigJSON
+  | AdminAction.SetAdminJSON
+
+export { BatchAuction } from "./BatchAuction"
+export type { BatchAuctionFields, BatchAuctionJSON } from "./BatchAuction"
+export { CompletionThreshold }
+
+export type CompletionThresholdKind =
+  | CompletionThreshold.Disabled
+  | CompletionThreshold.SolRaised
+  | CompletionThreshold.TokensSold
+export type CompletionThresholdJSON =
+  | CompletionThreshold.DisabledJSON
+  | CompletionThreshold.SolRaisedJSON
+  | CompletionThreshold.TokensSoldJSON
+
+export { ConfigUpdate } from "./ConfigUpdate"
+export type { ConfigUpdateFields, ConfigUpdateJSON } from "./ConfigUpdate"
+export { CurveType }
+
+export type CurveTypeKind =
+  | CurveType.ConstantProduct
+  | CurveType.Linear
+  | CurveType.Exponential
+  | CurveType.Sigmoid
+export type CurveTypeJSON =
+  | CurveType.ConstantProductJSON
+  | CurveType.LinearJSON
+  | CurveType.ExponentialJSON
+  | CurveType.SigmoidJSON
+
+export { LaunchMode }
+
+export type LaunchModeKind =
+  | LaunchMode.Instant
+  | LaunchMode.Presale
+  | LaunchMode.DutchAuction
+  | LaunchMode.BatchAuction
+export type LaunchModeJSON =
+  | LaunchMode.InstantJSON
+  | LaunchMode.PresaleJSON
+  | LaunchMode.DutchAuctionJSON
+  | LaunchMode.BatchAuctionJSON
+
+export { DutchAuction } from "./DutchAuction"
+export type { DutchAuctionFields, DutchAuctionJSON } from "./DutchAuction"
+export { MigrationTarget }
+
+export type MigrationTargetKind =
+  | MigrationTarget.Raydium
+  | MigrationTarget.Meteora
+export type MigrationTargetJSON =
+  | MigrationTarget.RaydiumJSON
+  | MigrationTarget.MeteoraJSON
+
+export { PauseState }
+
+export type PauseStateKind =
+  | PauseState.Unpaused
+  | PauseState.Paused
+  | PauseState.WithdrawalsOnly
+export type PauseStateJSON =
+  | PauseState.UnpausedJSON
+  | PauseState.PausedJSON
+  | PauseState.WithdrawalsOnlyJSON
+
+export { PoolStatus }
+
+export type PoolStatusKind =
+  | PoolStatus.Active
+  | PoolStatus.Completed
+  | PoolStatus.Migrated
+  | PoolStatus.Presale
+  | PoolStatus.Cancelled
+  | PoolStatus.Auction
+  | PoolStatus.Batching
+export type PoolStatusJSON =
+  | PoolStatus.ActiveJSON
+  | PoolStatus.CompletedJSON
+  | PoolStatus.MigratedJSON
+  | PoolStatus.PresaleJSON
+  | PoolStatus.CancelledJSON
+  | PoolStatus.AuctionJSON
+  | PoolStatus.BatchingJSON
+
+export { Presale } from "./Presale"
+export type { PresaleFields, PresaleJSON } from "./Presale"
+export { TradeDirection }
+
+export type TradeDirectionKind = TradeDirection.Buy | TradeDirection.Sell
+export type TradeDirectionJSON =
+  | TradeDirection.BuyJSON
+  | TradeDirection.SellJSON
@@ -1 +1,5 @@
-pub const INITIAL_PRICE: u64 = 600;       // lamports per one token (without decimal)
\ No newline at end of file
+pub const INITIAL_PRICE: u64 = 600; // lamports per one token (without decimal)
+
+// Tokens launched with create_token
+pub const TOKEN_DECIMALS: u8 = 6;
+pub const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000_000_000; // one billion whole tokens
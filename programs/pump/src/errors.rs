@@ -31,4 +31,127 @@ pub enum CustomError {
 
     #[msg("Overflow or underflow occured")]
     OverflowOrUnderflowOccurred,
+
+    #[msg("Invalid configuration account")]
+    InvalidConfigurationAccount,
+
+    #[msg("Output amount is below the minimum requested")]
+    SlippageExceeded,
+
+    #[msg("Input amount is above the maximum allowed")]
+    ExcessiveInputAmount,
+
+    #[msg("Invalid trade direction")]
+    InvalidTradeDirection,
+
+    #[msg("SOL vault would fall below its rent-exempt minimum")]
+    VaultBelowRentExemption,
+
+    #[msg("Invalid curve parameters")]
+    InvalidCurveParameters,
+
+    #[msg("Invalid completion threshold")]
+    InvalidCompletionThreshold,
+
+    #[msg("Pool is not accepting trades")]
+    PoolNotActive,
+
+    #[msg("Pool curve has not completed")]
+    PoolNotCompleted,
+
+    #[msg("Pool was launched for a different migration target")]
+    InvalidMigrationTarget,
+
+    #[msg("Invalid presale parameters")]
+    InvalidPresaleParameters,
+
+    #[msg("Presale is not accepting bids")]
+    PresaleNotOpen,
+
+    #[msg("Presale window has not ended")]
+    PresaleNotEnded,
+
+    #[msg("Presale has not launched")]
+    PresaleNotLaunched,
+
+    #[msg("Presale bids are only refunded once the presale is cancelled")]
+    PresaleNotRefundable,
+
+    #[msg("Wallet is not on the presale allowlist")]
+    NotAllowlisted,
+
+    #[msg("Deposit would exceed the wallet's presale allocation")]
+    PresaleAllocationExceeded,
+
+    #[msg("Invalid auction parameters")]
+    InvalidAuctionParameters,
+
+    #[msg("Auction is not selling")]
+    AuctionNotOpen,
+
+    #[msg("Auction window has not ended")]
+    AuctionNotEnded,
+
+    #[msg("Invalid batch auction parameters")]
+    InvalidBatchAuctionParameters,
+
+    #[msg("Batch auction is not taking orders")]
+    BatchAuctionNotOpen,
+
+    #[msg("Batch auction window has not ended")]
+    BatchAuctionNotEnded,
+
+    #[msg("No admin handover is pending")]
+    NoPendingAdmin,
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Paused")]
+    Paused,
+
+    #[msg("Invalid multisig signers or threshold")]
+    InvalidMultisig,
+
+    #[msg("Configuration is not governed by a multisig")]
+    MultisigNotEnabled,
+
+    #[msg("Signer is not in the multisig")]
+    NotMultisigSigner,
+
+    #[msg("Signer already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Proposal was already executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+
+    #[msg("Accounts do not match the proposal")]
+    InvalidProposalAccounts,
+
+    #[msg("Invalid update delay")]
+    InvalidUpdateDelay,
+
+    #[msg("Queued update is not effective yet")]
+    UpdateNotEffective,
+
+    #[msg("Only the mint authority or the admin can create its pool")]
+    UnauthorizedPoolCreator,
+
+    #[msg("Exact output exceeds the supply left before completion")]
+    ExactOutputUnavailable,
+
+    #[msg("Only the program's upgrade authority can migrate the configuration")]
+    NotUpgradeAuthority,
+
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+
+    #[msg("Proposal was created for a previous signer set")]
+    ProposalStale,
+
+    #[msg("Proposal is still open")]
+    ProposalStillOpen,
 }
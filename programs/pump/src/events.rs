@@ -0,0 +1,64 @@
+use crate::state::ConfigUpdate;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct MultisigSet {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub id: u64,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub id: u64,
+}
+
+#[event]
+pub struct ProposalClosed {
+    pub id: u64,
+}
+
+#[event]
+pub struct ConfigUpdateQueued {
+    pub update: ConfigUpdate,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct ConfigUpdateExecuted {
+    pub update: ConfigUpdate,
+}
+
+#[event]
+pub struct ConfigUpdateCancelled {
+    pub update: ConfigUpdate,
+}
@@ -0,0 +1,38 @@
+use crate::{errors::CustomError, events::AdminAccepted, state::*};
+use anchor_lang::prelude::*;
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    let new_admin = ctx.accounts.new_admin.key();
+
+    match dex_config.pending_admin {
+        None => return err!(CustomError::NoPendingAdmin),
+        Some(pending_admin) if pending_admin != new_admin => {
+            return err!(CustomError::NotPendingAdmin)
+        }
+        _ => {}
+    }
+
+    // a multisig that proposed the key hands over control with it
+    let previous_admin = dex_config.admin;
+    dex_config.set_admin(new_admin);
+
+    emit!(AdminAccepted {
+        previous_admin,
+        admin: new_admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub new_admin: Signer<'info>,
+}
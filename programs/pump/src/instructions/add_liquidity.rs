@@ -6,11 +6,12 @@ use anchor_spl::{
 
 use crate::{
     // errors::CustomError,
-    state::{LiquidityPool, LiquidityPoolAccount, LiquidityProvider},
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, LiquidityProvider},
 };
 
 pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u64) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
 
     // (token, to, from)
     let token_one_accounts = (
@@ -18,6 +19,7 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u6
         &mut *ctx.accounts.pool_token_account_one,
         &mut *ctx.accounts.user_token_account_one,
     );
+    let sol_vault = &ctx.accounts.sol_vault.to_account_info();
 
     // let token_two_accounts = (
     //     &mut *ctx.accounts.mint_token_one.clone(),
@@ -25,20 +27,17 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u6
     //     &mut ctx.accounts.user.to_account_info().clone(),
     // );
 
-    pool.set_inner(LiquidityPool::new(
-        ctx.accounts.mint_token_one.key(),
-        ctx.bumps.pool,
-    ));
-
     // amount two is sol amount
     pool.add_liquidity(
         token_one_accounts,
         // token_two_accounts,
+        sol_vault,
         amount_one,
         amount_two,
-        &mut *ctx.accounts.liquidity_provider_account,
+        &mut ctx.accounts.liquidity_provider_account,
         &ctx.accounts.user,
         &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
     )?;
 
     Ok(())
@@ -47,14 +46,25 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u6
 #[derive(Accounts)]
 pub struct AddLiquidity<'info> {
     #[account(
-        init,
-        space = LiquidityPool::ACCOUNT_SIZE,
-        payer = user,
-        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()], // each mint_token refer to one pool account
-        bump
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
     )]
     pub pool: Box<Account<'info, LiquidityPool>>,
 
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -68,8 +78,7 @@ pub struct AddLiquidity<'info> {
     pub mint_token_one: Box<Account<'info, Mint>>,
 
     #[account(
-        init,
-        payer = user,
+        mut,
         associated_token::mint = mint_token_one,
         associated_token::authority = pool
     )]
@@ -0,0 +1,37 @@
+use crate::{events::ProposalApproved, state::*};
+use anchor_lang::prelude::*;
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let dex_config = &ctx.accounts.dex_configuration_account;
+    let signer = ctx.accounts.signer.key();
+
+    dex_config.ensure_multisig_signer(&signer)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approve(dex_config, signer, Clock::get()?.unix_timestamp)?;
+
+    emit!(ProposalApproved {
+        id: proposal.id,
+        signer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [Proposal::SEED_PREFIX.as_bytes(), proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub signer: Signer<'info>,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount},
+};
+
+pub fn auction_buy(ctx: Context<AuctionBuy>, amount: u64, max_sol_in: u64) -> Result<()> {
+    let threshold = ctx.accounts.dex_configuration_account.completion_threshold;
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
+
+    let (tokens, cost) = pool.record_auction_buy(threshold, amount, Clock::get()?.slot)?;
+    if cost > max_sol_in {
+        return err!(CustomError::SlippageExceeded);
+    }
+
+    pool.transfer_sol_to_pool(
+        &ctx.accounts.user,
+        &ctx.accounts.sol_vault.to_account_info(),
+        cost,
+        &ctx.accounts.system_program,
+    )?;
+
+    pool.transfer_token_from_pool(
+        &ctx.accounts.pool_token_account_one,
+        &ctx.accounts.user_token_account_one,
+        tokens,
+        &ctx.accounts.token_program,
+    )?;
+
+    msg!(
+        "Auction buy: {:?} {:?} {:?}",
+        ctx.accounts.user.key(),
+        tokens,
+        cost
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AuctionBuy<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = user,
+    )]
+    pub user_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,31 @@
+use crate::{errors::CustomError, events::AdminProposalCancelled, state::*};
+use anchor_lang::prelude::*;
+
+pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+
+    let pending_admin = dex_config
+        .pending_admin
+        .take()
+        .ok_or(CustomError::NoPendingAdmin)?;
+
+    emit!(AdminProposalCancelled {
+        admin: dex_config.admin,
+        pending_admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,34 @@
+use crate::{events::ConfigUpdateCancelled, state::*};
+use anchor_lang::prelude::*;
+
+pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+    emit!(ConfigUpdateCancelled {
+        update: ctx.accounts.pending_update.update,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [PendingUpdate::SEED_PREFIX.as_bytes()],
+        bump,
+        has_one = payer,
+        close = payer
+    )]
+    pub pending_update: Box<Account<'info, PendingUpdate>>,
+
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
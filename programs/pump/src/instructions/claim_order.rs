@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::state::{BatchOrder, CurveConfiguration, LiquidityPool, LiquidityPoolAccount};
+
+pub fn claim_order(ctx: Context<ClaimOrder>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, true)?;
+
+    let (tokens, refund) = pool.claim_batch_order(ctx.accounts.batch_order.lamports)?;
+
+    // a cancelled batch has no tokens to hand out
+    if tokens > 0 {
+        pool.transfer_token_from_pool(
+            &ctx.accounts.pool_token_account_one,
+            &ctx.accounts.user_token_account_one,
+            tokens,
+            &ctx.accounts.token_program,
+        )?;
+    }
+
+    // only SOL the clearing could not spend is paid back
+    if refund > 0 {
+        pool.transfer_sol_from_pool(
+            &ctx.accounts.sol_vault.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            refund,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    msg!(
+        "Batch claim: {:?} {:?} {:?}",
+        ctx.accounts.user.key(),
+        tokens,
+        refund
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimOrder<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [BatchOrder::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub batch_order: Box<Account<'info, BatchOrder>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = user,
+    )]
+    pub user_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
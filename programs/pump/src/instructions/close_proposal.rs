@@ -0,0 +1,37 @@
+use crate::{events::ProposalClosed, state::*};
+use anchor_lang::prelude::*;
+
+// Anyone can close a proposal that can no longer run, refunding its rent to the proposer
+pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    proposal.ensure_closable(
+        &ctx.accounts.dex_configuration_account,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    emit!(ProposalClosed { id: proposal.id });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [Proposal::SEED_PREFIX.as_bytes(), proposal.id.to_le_bytes().as_ref()],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, CurveType, LaunchMode, LiquidityPool, MigrationTarget},
+    utils::fund_sol_vault,
+};
+
+pub fn create_pool(
+    ctx: Context<CreateLiquidityPool>,
+    curve_type: CurveType,
+    curve_params: [u64; 3],
+    migration_target: MigrationTarget,
+    launch_mode: LaunchMode,
+) -> Result<()> {
+    // only the mint's authority or the admin decides how a mint launches
+    let payer = ctx.accounts.payer.key();
+    if ctx.accounts.mint_token_one.mint_authority != COption::Some(payer)
+        && ctx.accounts.dex_configuration_account.admin != payer
+    {
+        return err!(CustomError::UnauthorizedPoolCreator);
+    }
+
+    let pool = &mut ctx.accounts.pool;
+
+    curve_type.validate_params(&curve_params)?;
+
+    pool.set_inner(LiquidityPool::new(
+        ctx.accounts.mint_token_one.key(),
+        ctx.bumps.pool,
+        ctx.bumps.sol_vault,
+        &ctx.accounts.dex_configuration_account,
+        curve_type,
+        curve_params,
+        migration_target,
+    ));
+    pool.set_launch_mode(launch_mode)?;
+
+    fund_sol_vault(
+        &ctx.accounts.payer,
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateLiquidityPool<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = LiquidityPool::ACCOUNT_SIZE,
+        payer = payer,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()], // each mint_token refer to one pool account
+        bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
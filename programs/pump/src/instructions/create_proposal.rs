@@ -0,0 +1,50 @@
+use crate::{events::ProposalCreated, state::*};
+use anchor_lang::prelude::*;
+
+pub fn create_proposal(ctx: Context<CreateProposal>, action: AdminAction) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    let proposer = ctx.accounts.proposer.key();
+
+    dex_config.ensure_multisig_signer(&proposer)?;
+
+    let id = dex_config.proposal_count;
+    dex_config.proposal_count += 1;
+
+    ctx.accounts.proposal.set_inner(Proposal::new(
+        id,
+        proposer,
+        action,
+        dex_config,
+        Clock::get()?.unix_timestamp,
+    )?);
+
+    emit!(ProposalCreated { id, proposer });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = Proposal::ACCOUNT_SIZE,
+        payer = proposer,
+        seeds = [
+            Proposal::SEED_PREFIX.as_bytes(),
+            dex_configuration_account.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
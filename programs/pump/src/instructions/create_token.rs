@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{self, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3, Metadata},
+    token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    consts::{TOKEN_DECIMALS, TOKEN_TOTAL_SUPPLY},
+    state::{CurveConfiguration, CurveType, LaunchMode, LiquidityPool, MigrationTarget},
+    utils::fund_sol_vault,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_token(
+    ctx: Context<CreateToken>,
+    name: String,
+    symbol: String,
+    uri: String,
+    curve_type: CurveType,
+    curve_params: [u64; 3],
+    migration_target: MigrationTarget,
+    launch_mode: LaunchMode,
+) -> Result<()> {
+    curve_type.validate_params(&curve_params)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.set_inner(LiquidityPool::new(
+        ctx.accounts.mint.key(),
+        ctx.bumps.pool,
+        ctx.bumps.sol_vault,
+        &ctx.accounts.dex_configuration_account,
+        curve_type,
+        curve_params,
+        migration_target,
+    ));
+    pool.seed_token_supply(TOKEN_TOTAL_SUPPLY)?;
+    pool.set_launch_mode(launch_mode)?;
+
+    fund_sol_vault(
+        &ctx.accounts.payer,
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LiquidityPool::POOL_SEED_PREFIX.as_bytes(),
+        mint_key.as_ref(),
+        &[ctx.bumps.pool],
+    ]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.pool_token_account_one.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        TOKEN_TOTAL_SUPPLY,
+    )?;
+
+    // the pool signs as update authority and the metadata is immutable
+    metadata::create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.pool.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: ctx.accounts.pool.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false,
+        true,
+        None,
+    )?;
+
+    // with both authorities gone the supply is fixed and no holder can be frozen
+    for authority_type in [AuthorityType::MintTokens, AuthorityType::FreezeAccount] {
+        token::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::SetAuthority {
+                    current_authority: ctx.accounts.pool.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            authority_type,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateToken<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = LiquidityPool::ACCOUNT_SIZE,
+        payer = payer,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = TOKEN_DECIMALS,
+        mint::authority = pool,
+        mint::freeze_authority = pool,
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: initialized by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
@@ -0,0 +1,41 @@
+use crate::{events::ConfigUpdateExecuted, state::*};
+use anchor_lang::prelude::*;
+
+// Anyone can apply a queued update once its delay passed
+pub fn execute_config_update(ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+    let pending_update = &ctx.accounts.pending_update;
+
+    pending_update.ensure_effective(Clock::get()?.unix_timestamp)?;
+
+    ctx.accounts
+        .dex_configuration_account
+        .update(&pending_update.update)?;
+
+    emit!(ConfigUpdateExecuted {
+        update: pending_update.update,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteConfigUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [PendingUpdate::SEED_PREFIX.as_bytes()],
+        bump,
+        has_one = payer,
+        close = payer
+    )]
+    pub pending_update: Box<Account<'info, PendingUpdate>>,
+
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
@@ -0,0 +1,132 @@
+use crate::{
+    errors::CustomError,
+    events::{AdminProposed, ConfigUpdateCancelled, ConfigUpdateQueued, ProposalExecuted},
+    state::*,
+};
+use anchor_lang::prelude::*;
+
+// Anyone can execute once enough current signers approved.
+// UpdateConfig queues into pending_update and SetAdmin waits for the new key
+// to accept, the other actions apply right away.
+// SetPoolPause and ReopenPool take the pool as the first remaining account,
+// CancelConfigUpdate the pending update and its payer.
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    let proposal = &mut ctx.accounts.proposal;
+
+    let now = Clock::get()?.unix_timestamp;
+    proposal.ensure_approved(dex_config, now)?;
+
+    // an update queued by any other action would be empty
+    let queues_update = matches!(proposal.action, AdminAction::UpdateConfig { .. });
+    if queues_update != ctx.accounts.pending_update.is_some() {
+        return err!(CustomError::InvalidProposalAccounts);
+    }
+
+    match proposal.action.clone() {
+        AdminAction::UpdateConfig { update } => {
+            let pending_update =
+                PendingUpdate::new(update, dex_config, ctx.accounts.executor.key(), now)?;
+
+            emit!(ConfigUpdateQueued {
+                update: pending_update.update,
+                effective_at: pending_update.effective_at,
+            });
+
+            if let Some(account) = ctx.accounts.pending_update.as_mut() {
+                account.set_inner(pending_update);
+            }
+        }
+        AdminAction::CancelConfigUpdate => {
+            let (pending_info, payer_info) = match ctx.remaining_accounts {
+                [pending_info, payer_info, ..] => (pending_info, payer_info),
+                _ => return err!(CustomError::InvalidProposalAccounts),
+            };
+
+            let (pending_key, _) =
+                Pubkey::find_program_address(&[PendingUpdate::SEED_PREFIX.as_bytes()], &crate::ID);
+            let pending_update = Account::<PendingUpdate>::try_from(pending_info)?;
+            if pending_info.key() != pending_key || payer_info.key() != pending_update.payer {
+                return err!(CustomError::InvalidProposalAccounts);
+            }
+
+            emit!(ConfigUpdateCancelled {
+                update: pending_update.update,
+            });
+
+            pending_update.close(payer_info.clone())?;
+        }
+        AdminAction::SetPause { pause } => dex_config.pause = pause,
+        AdminAction::SetPoolPause { pool, pause } => {
+            let pool_info = match ctx.remaining_accounts.first() {
+                Some(pool_info) if pool_info.key() == pool => pool_info,
+                _ => return err!(CustomError::InvalidProposalAccounts),
+            };
+
+            let mut pool = Account::<LiquidityPool>::try_from(pool_info)?;
+            pool.pause = pause;
+            pool.exit(&crate::ID)?;
+        }
+        AdminAction::ReopenPool { pool } => {
+            let pool_info = match ctx.remaining_accounts.first() {
+                Some(pool_info) if pool_info.key() == pool => pool_info,
+                _ => return err!(CustomError::InvalidProposalAccounts),
+            };
+
+            let mut pool = Account::<LiquidityPool>::try_from(pool_info)?;
+            pool.reopen()?;
+            pool.exit(&crate::ID)?;
+        }
+        AdminAction::SetMultisig { signers, threshold } => {
+            dex_config.set_multisig(signers, threshold)?
+        }
+        // the signers stay in charge until the new key accepts
+        AdminAction::SetAdmin { admin } => {
+            dex_config.pending_admin = Some(admin);
+
+            emit!(AdminProposed {
+                admin: dex_config.admin,
+                pending_admin: admin,
+            });
+        }
+    }
+
+    proposal.executed = true;
+
+    emit!(ProposalExecuted { id: proposal.id });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [Proposal::SEED_PREFIX.as_bytes(), proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    // only for UpdateConfig proposals
+    #[account(
+        init,
+        space = PendingUpdate::ACCOUNT_SIZE,
+        payer = executor,
+        seeds = [PendingUpdate::SEED_PREFIX.as_bytes()],
+        bump
+    )]
+    pub pending_update: Option<Box<Account<'info, PendingUpdate>>>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
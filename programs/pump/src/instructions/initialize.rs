@@ -1,17 +1,44 @@
-use crate::{errors::CustomError, state::*};
+use crate::{errors::CustomError, program::Pump, state::*};
 use anchor_lang::prelude::*;
 
+// Only the program's upgrade authority can create the configuration, so nobody can
+// front-run the deployment and make themselves admin. It names the admin, which
+// does not have to be itself.
+#[allow(clippy::too_many_arguments)]
 pub fn initialize(
     ctx: Context<InitializeCurveConfiguration>,
-    fees: f64,
+    buy_fee_bps: u16,
+    sell_fee_bps: u16,
+    initial_virtual_sol_reserves: u64,
+    initial_virtual_token_reserves: u64,
+    completion_threshold: CompletionThreshold,
+    min_update_delay: i64,
+    raydium_amm_config: Pubkey,
 ) -> Result<()> {
+    if ctx.accounts.program_data.upgrade_authority_address != Some(ctx.accounts.authority.key()) {
+        return err!(CustomError::NotUpgradeAuthority);
+    }
+
     let dex_config = &mut ctx.accounts.dex_configuration_account;
 
-    if fees < 0_f64 || fees > 100_f64 {
-        return err!(CustomError::InvalidFee);
-    }
+    CurveConfiguration::validate_fees(buy_fee_bps, sell_fee_bps)?;
+    completion_threshold.validate()?;
+    CurveConfiguration::validate_update_delay(min_update_delay)?;
 
-    dex_config.set_inner(CurveConfiguration::new(fees));
+    dex_config.set_inner(CurveConfiguration {
+        raydium_amm_config,
+        ..CurveConfiguration::new(
+            buy_fee_bps,
+            sell_fee_bps,
+            initial_virtual_sol_reserves,
+            initial_virtual_token_reserves,
+            completion_threshold,
+            ctx.accounts.admin.key(),
+            // until the admin points it elsewhere
+            ctx.accounts.admin.key(),
+            min_update_delay,
+        )
+    });
 
     Ok(())
 }
@@ -27,6 +54,13 @@ pub struct InitializeCurveConfiguration<'info> {
     )]
     pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
 
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Pump>,
+
+    pub program_data: Account<'info, ProgramData>,
+
+    pub authority: Signer<'info>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
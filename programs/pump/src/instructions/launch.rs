@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, PoolStatus};
+
+pub fn launch(ctx: Context<Launch>) -> Result<()> {
+    let config = &ctx.accounts.dex_configuration_account;
+    let threshold = config.completion_threshold;
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(config, false)?;
+
+    let slot = Clock::get()?.slot;
+
+    // auctions launch by settling, everything else goes through the presale
+    let fee = match pool.status {
+        PoolStatus::Auction => {
+            pool.end_auction(threshold, slot)?;
+            0
+        }
+        PoolStatus::Batching => pool.clear_batch_auction(threshold, config.buy_fee_bps, slot)?,
+        _ => pool.launch_presale(threshold, config.buy_fee_bps, slot)?,
+    };
+
+    // the opening buy pays the treasury like a swap would
+    if fee > 0 {
+        pool.transfer_sol_from_pool(
+            &ctx.accounts.sol_vault.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            fee,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Launch<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(mut, address = dex_configuration_account.treasury)]
+    pub treasury: SystemAccount<'info>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+}
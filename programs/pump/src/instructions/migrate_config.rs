@@ -0,0 +1,71 @@
+use crate::{errors::CustomError, program::Pump, state::*};
+use anchor_lang::{prelude::*, system_program};
+
+// Converts a configuration account written with the legacy f64 percentage fee
+// into the current layout. The legacy layout had no admin, so the program's
+// upgrade authority claims it, and had no update delay, so it sets one.
+pub fn migrate_config(
+    ctx: Context<MigrateCurveConfiguration>,
+    min_update_delay: i64,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    if ctx.accounts.program_data.upgrade_authority_address != Some(authority) {
+        return err!(CustomError::NotUpgradeAuthority);
+    }
+
+    CurveConfiguration::validate_update_delay(min_update_delay)?;
+
+    let dex_config = ctx.accounts.dex_configuration_account.to_account_info();
+    let migrated = CurveConfiguration::from_legacy(
+        &dex_config.try_borrow_data()?,
+        authority,
+        min_update_delay,
+    )?;
+    msg!("Legacy fee migrated: {:?} bps", migrated.buy_fee_bps);
+
+    let minimum_balance = Rent::get()?.minimum_balance(CurveConfiguration::ACCOUNT_SIZE);
+    let current_balance = dex_config.lamports();
+    if minimum_balance > current_balance {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: dex_config.clone(),
+                },
+            ),
+            minimum_balance - current_balance,
+        )?;
+    }
+
+    dex_config.realloc(CurveConfiguration::ACCOUNT_SIZE, false)?;
+
+    let mut data = dex_config.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateCurveConfiguration<'info> {
+    /// CHECK: deserialized by hand since it still holds the legacy layout
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub dex_configuration_account: UncheckedAccount<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Pump>,
+
+    pub program_data: Account<'info, ProgramData>,
+
+    // becomes the admin of the migrated configuration
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::native_mint, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, MigrationTarget},
+    utils::{burn_lp_tokens, invoke_external},
+};
+
+pub mod meteora_amm {
+    use anchor_lang::prelude::*;
+
+    declare_id!("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB");
+
+    // sha256("global:initialize_permissionless_pool")[..8]
+    pub const INITIALIZE_PERMISSIONLESS_POOL_DISCRIMINATOR: [u8; 8] =
+        [118, 173, 41, 157, 173, 72, 97, 103];
+
+    // Borsh tag of CurveType::ConstantProduct
+    pub const CONSTANT_PRODUCT_CURVE: u8 = 0;
+
+    // The constant product pool Meteora creates for two mints, the larger key first
+    pub fn pool_address(mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+        let (first, second) = if mint_a > mint_b {
+            (mint_a, mint_b)
+        } else {
+            (mint_b, mint_a)
+        };
+
+        Pubkey::find_program_address(&[first.as_ref(), second.as_ref()], &ID).0
+    }
+}
+
+pub fn migrate_to_meteora(ctx: Context<MigrateToMeteora>) -> Result<()> {
+    ctx.accounts
+        .pool
+        .ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
+
+    // the payer fronts both legs as the Meteora pool creator, within this instruction only
+    let (token_amount, sol_amount) = ctx.accounts.pool.release_for_migration(
+        MigrationTarget::Meteora,
+        &ctx.accounts.pool_token_account_one,
+        &ctx.accounts.payer_token_account,
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.payer_wsol_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    let accounts = &ctx.accounts;
+    let mut data = meteora_amm::INITIALIZE_PERMISSIONLESS_POOL_DISCRIMINATOR.to_vec();
+    // token A is the launched token, token B is WSOL
+    (
+        meteora_amm::CONSTANT_PRODUCT_CURVE,
+        token_amount,
+        sol_amount,
+    )
+        .serialize(&mut data)?;
+
+    invoke_external(
+        &accounts.meteora_program,
+        data,
+        &[
+            (accounts.meteora_pool.to_account_info(), true),
+            (accounts.lp_mint.to_account_info(), true),
+            (accounts.mint_token_one.to_account_info(), false),
+            (accounts.wsol_mint.to_account_info(), false),
+            (accounts.a_vault.to_account_info(), true),
+            (accounts.b_vault.to_account_info(), true),
+            (accounts.a_vault_lp_mint.to_account_info(), true),
+            (accounts.b_vault_lp_mint.to_account_info(), true),
+            (accounts.a_vault_lp.to_account_info(), true),
+            (accounts.b_vault_lp.to_account_info(), true),
+            (accounts.payer_token_account.to_account_info(), true),
+            (accounts.payer_wsol_account.to_account_info(), true),
+            (accounts.payer_lp_token.to_account_info(), true),
+            (accounts.protocol_token_a_fee.to_account_info(), true),
+            (accounts.protocol_token_b_fee.to_account_info(), true),
+            (accounts.payer.to_account_info(), true),
+            (accounts.fee_owner.to_account_info(), false),
+            (accounts.rent.to_account_info(), false),
+            (accounts.mint_metadata.to_account_info(), true),
+            (accounts.metadata_program.to_account_info(), false),
+            (accounts.vault_program.to_account_info(), false),
+            (accounts.token_program.to_account_info(), false),
+            (accounts.associated_token_program.to_account_info(), false),
+            (accounts.system_program.to_account_info(), false),
+        ],
+    )?;
+
+    burn_lp_tokens(
+        &accounts.payer_lp_token,
+        &accounts.lp_mint,
+        &accounts.payer,
+        &accounts.token_program,
+    )?;
+
+    msg!(
+        "Migrated {:?} to Meteora: {:?} tokens, {:?} lamports",
+        accounts.mint_token_one.key(),
+        token_amount,
+        sol_amount
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateToMeteora<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = native_mint::ID)]
+    pub wsol_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint_token_one,
+        token::authority = payer,
+    )]
+    pub payer_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = payer,
+    )]
+    pub payer_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: created by Meteora as the creator's LP token account
+    #[account(mut)]
+    pub payer_lp_token: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora, at the pool address of the two mints
+    #[account(
+        mut,
+        address = meteora_amm::pool_address(&mint_token_one.key(), &wsol_mint.key())
+    )]
+    pub meteora_pool: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    #[account(mut)]
+    pub a_vault: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    #[account(mut)]
+    pub b_vault: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    #[account(mut)]
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    #[account(mut)]
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora
+    #[account(mut)]
+    pub a_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora
+    #[account(mut)]
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora
+    #[account(mut)]
+    pub protocol_token_a_fee: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora
+    #[account(mut)]
+    pub protocol_token_b_fee: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    pub fee_owner: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Meteora
+    #[account(mut)]
+    pub mint_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    pub metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Meteora
+    pub vault_program: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the Meteora dynamic AMM program id
+    #[account(address = meteora_amm::ID)]
+    pub meteora_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::native_mint, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, MigrationTarget},
+    utils::{burn_lp_tokens, invoke_external},
+};
+
+pub mod raydium_cpmm {
+    use anchor_lang::prelude::*;
+
+    declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+    // sha256("global:initialize")[..8]
+    pub const INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+    pub const POOL_SEED: &str = "pool";
+
+    // The pool Raydium creates for two mints under a fee tier, mints in ascending order
+    pub fn pool_state_address(amm_config: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+        let (mint_0, mint_1) = if mint_a < mint_b {
+            (mint_a, mint_b)
+        } else {
+            (mint_b, mint_a)
+        };
+
+        Pubkey::find_program_address(
+            &[
+                POOL_SEED.as_bytes(),
+                amm_config.as_ref(),
+                mint_0.as_ref(),
+                mint_1.as_ref(),
+            ],
+            &ID,
+        )
+        .0
+    }
+}
+
+pub fn migrate_to_raydium(ctx: Context<MigrateToRaydium>) -> Result<()> {
+    ctx.accounts
+        .pool
+        .ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
+
+    // the payer fronts both legs as Raydium's pool creator, within this instruction only
+    let (token_amount, sol_amount) = ctx.accounts.pool.release_for_migration(
+        MigrationTarget::Raydium,
+        &ctx.accounts.pool_token_account_one,
+        &ctx.accounts.payer_token_account,
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.payer_wsol_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Raydium expects the two mints in ascending order
+    let token_mint = ctx.accounts.mint_token_one.to_account_info();
+    let wsol_mint = ctx.accounts.wsol_mint.to_account_info();
+    let token_account = ctx.accounts.payer_token_account.to_account_info();
+    let wsol_account = ctx.accounts.payer_wsol_account.to_account_info();
+    let (mints, creator_accounts, amounts) = if token_mint.key() < wsol_mint.key() {
+        (
+            [token_mint, wsol_mint],
+            [token_account, wsol_account],
+            [token_amount, sol_amount],
+        )
+    } else {
+        (
+            [wsol_mint, token_mint],
+            [wsol_account, token_account],
+            [sol_amount, token_amount],
+        )
+    };
+
+    let accounts = &ctx.accounts;
+    let mut data = raydium_cpmm::INITIALIZE_DISCRIMINATOR.to_vec();
+    // init_amount_0, init_amount_1, then an open time of zero to trade right away
+    (amounts[0], amounts[1], 0_u64).serialize(&mut data)?;
+
+    invoke_external(
+        &accounts.raydium_program,
+        data,
+        &[
+            (accounts.payer.to_account_info(), true),
+            (accounts.amm_config.to_account_info(), false),
+            (accounts.raydium_authority.to_account_info(), false),
+            (accounts.raydium_pool_state.to_account_info(), true),
+            (mints[0].clone(), false),
+            (mints[1].clone(), false),
+            (accounts.lp_mint.to_account_info(), true),
+            (creator_accounts[0].clone(), true),
+            (creator_accounts[1].clone(), true),
+            (accounts.payer_lp_token.to_account_info(), true),
+            (accounts.token_0_vault.to_account_info(), true),
+            (accounts.token_1_vault.to_account_info(), true),
+            (accounts.create_pool_fee.to_account_info(), true),
+            (accounts.observation_state.to_account_info(), true),
+            (accounts.token_program.to_account_info(), false),
+            (accounts.token_program.to_account_info(), false),
+            (accounts.token_program.to_account_info(), false),
+            (accounts.associated_token_program.to_account_info(), false),
+            (accounts.system_program.to_account_info(), false),
+            (accounts.rent.to_account_info(), false),
+        ],
+    )?;
+
+    burn_lp_tokens(
+        &accounts.payer_lp_token,
+        &accounts.lp_mint,
+        &accounts.payer,
+        &accounts.token_program,
+    )?;
+
+    msg!(
+        "Migrated {:?} to Raydium: {:?} tokens, {:?} lamports",
+        accounts.mint_token_one.key(),
+        token_amount,
+        sol_amount
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateToRaydium<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = native_mint::ID)]
+    pub wsol_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint_token_one,
+        token::authority = payer,
+    )]
+    pub payer_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = payer,
+    )]
+    pub payer_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: created by Raydium as the creator's LP token account
+    #[account(mut)]
+    pub payer_lp_token: UncheckedAccount<'info>,
+
+    /// CHECK: the fee tier the admin picked, validated by Raydium
+    #[account(address = dex_configuration_account.raydium_amm_config)]
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Raydium
+    pub raydium_authority: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Raydium, at the pool address of the pinned fee tier
+    #[account(
+        mut,
+        address = raydium_cpmm::pool_state_address(&amm_config.key(), &mint_token_one.key(), &wsol_mint.key())
+    )]
+    pub raydium_pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Raydium
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Raydium
+    #[account(mut)]
+    pub token_0_vault: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Raydium
+    #[account(mut)]
+    pub token_1_vault: UncheckedAccount<'info>,
+
+    /// CHECK: validated by Raydium
+    #[account(mut)]
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by Raydium
+    #[account(mut)]
+    pub observation_state: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the Raydium CPMM program id
+    #[account(address = raydium_cpmm::ID)]
+    pub raydium_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -1,9 +1,63 @@
+pub mod accept_admin;
 pub mod add_liquidity;
+pub mod approve_proposal;
+pub mod auction_buy;
+pub mod cancel_admin_proposal;
+pub mod cancel_config_update;
+pub mod claim_order;
+pub mod close_proposal;
+pub mod create_pool;
+pub mod create_proposal;
+pub mod create_token;
+pub mod execute_config_update;
+pub mod execute_proposal;
 pub mod initialize;
+pub mod launch;
+pub mod migrate_config;
+pub mod migrate_to_meteora;
+pub mod migrate_to_raydium;
+pub mod place_order;
+pub mod presale_claim;
+pub mod presale_deposit;
+pub mod presale_refund;
+pub mod propose_admin;
 pub mod remove_liquidity;
+pub mod reopen_pool;
+pub mod set_multisig;
+pub mod set_pause;
+pub mod set_pool_pause;
 pub mod swap;
+pub mod swap_exact_out;
+pub mod update_config;
 
+pub use accept_admin::*;
 pub use add_liquidity::*;
+pub use approve_proposal::*;
+pub use auction_buy::*;
+pub use cancel_admin_proposal::*;
+pub use cancel_config_update::*;
+pub use claim_order::*;
+pub use close_proposal::*;
+pub use create_pool::*;
+pub use create_proposal::*;
+pub use create_token::*;
+pub use execute_config_update::*;
+pub use execute_proposal::*;
 pub use initialize::*;
+pub use launch::*;
+pub use migrate_config::*;
+pub use migrate_to_meteora::*;
+pub use migrate_to_raydium::*;
+pub use place_order::*;
+pub use presale_claim::*;
+pub use presale_deposit::*;
+pub use presale_refund::*;
+pub use propose_admin::*;
 pub use remove_liquidity::*;
+pub use reopen_pool::*;
+pub use set_multisig::*;
+pub use set_pause::*;
+pub use set_pool_pause::*;
 pub use swap::*;
+pub use swap_exact_out::*;
+pub use update_config::*;
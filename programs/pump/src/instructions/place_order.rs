@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{BatchOrder, CurveConfiguration, LiquidityPool, LiquidityPoolAccount},
+};
+
+pub fn place_order(ctx: Context<PlaceOrder>, lamports: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
+
+    pool.record_batch_order(lamports, Clock::get()?.slot)?;
+
+    let order = &mut ctx.accounts.batch_order;
+    order.lamports = order
+        .lamports
+        .checked_add(lamports)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    // orders wait in the SOL vault, outside the reserves, until the batch clears
+    pool.transfer_sol_to_pool(
+        &ctx.accounts.user,
+        &ctx.accounts.sol_vault.to_account_info(),
+        lamports,
+        &ctx.accounts.system_program,
+    )?;
+
+    msg!("Batch order: {:?} {:?}", ctx.accounts.user.key(), lamports);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PlaceOrder<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = BatchOrder::ACCOUNT_SIZE,
+        seeds = [BatchOrder::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub batch_order: Box<Account<'info, BatchOrder>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, PresaleBid},
+};
+
+pub fn presale_deposit(
+    ctx: Context<PresaleDeposit>,
+    amount: u64,
+    max_allocation: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
+
+    pool.record_presale_deposit(amount, Clock::get()?.slot)?;
+
+    let bid = &mut ctx.accounts.presale_bid;
+    bid.amount = bid
+        .amount
+        .checked_add(amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    // ignored unless the presale has an allowlist
+    pool.check_allowlist(&ctx.accounts.user.key(), bid.amount, max_allocation, &proof)?;
+
+    // bids wait in the SOL vault, outside the reserves, until launch
+    pool.transfer_sol_to_pool(
+        &ctx.accounts.user,
+        &ctx.accounts.sol_vault.to_account_info(),
+        amount,
+        &ctx.accounts.system_program,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PresaleDeposit<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PresaleBid::ACCOUNT_SIZE,
+        seeds = [PresaleBid::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub presale_bid: Box<Account<'info, PresaleBid>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, PresaleBid};
+
+pub fn presale_refund(ctx: Context<PresaleRefund>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, true)?;
+
+    let amount = ctx.accounts.presale_bid.amount;
+
+    pool.record_presale_refund(amount, Clock::get()?.slot)?;
+
+    pool.transfer_sol_from_pool(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+        &ctx.accounts.system_program,
+    )?;
+
+    msg!("Presale refund: {:?} {:?}", ctx.accounts.user.key(), amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PresaleRefund<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [PresaleBid::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub presale_bid: Box<Account<'info, PresaleBid>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,30 @@
+use crate::{events::AdminProposed, state::*};
+use anchor_lang::prelude::*;
+
+// Control only moves once the proposed key signs accept_admin,
+// so a mistyped key can be replaced or cancelled
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+
+    dex_config.pending_admin = Some(new_admin);
+
+    emit!(AdminProposed {
+        admin: dex_config.admin,
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
@@ -6,17 +6,19 @@ use anchor_spl::{
 
 use crate::{
     // errors::CustomError,
-    state::{LiquidityPool, LiquidityPoolAccount, LiquidityProvider},
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, LiquidityProvider},
 };
 
 pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, shares: u64) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, true)?;
 
     let token_one_accounts = (
         &mut *ctx.accounts.mint_token_one.clone(),
         &mut *ctx.accounts.pool_token_account_one,
         &mut *ctx.accounts.user_token_account_one,
     );
+    let sol_vault = &ctx.accounts.sol_vault.to_account_info();
 
     // let token_two_accounts = (
     //     &mut *ctx.accounts.mint_token_one.clone(),
@@ -27,10 +29,12 @@ pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, shares: u64) -> Result<()
     pool.remove_liquidity(
         token_one_accounts,
         // token_two_accounts,
+        sol_vault,
         shares,
-        &mut *ctx.accounts.liquidity_provider_account,
+        &mut ctx.accounts.liquidity_provider_account,
         &ctx.accounts.user,
         &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
     )?;
 
     Ok(())
@@ -38,6 +42,12 @@ pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, shares: u64) -> Result<()
 
 #[derive(Accounts)]
 pub struct RemoveLiquidity<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
     #[account(
         mut,
         seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
@@ -45,6 +55,13 @@ pub struct RemoveLiquidity<'info> {
     )]
     pub pool: Box<Account<'info, LiquidityPool>>,
 
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = user,
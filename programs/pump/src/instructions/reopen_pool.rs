@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::state::{CurveConfiguration, LiquidityPool};
+
+// Recovery for a completed pool whose migration keeps failing, e.g. because the AMM
+// pool it would create already exists. Trading resumes on the curve and the
+// liquidity can be withdrawn again.
+pub fn reopen_pool(ctx: Context<ReopenPool>) -> Result<()> {
+    ctx.accounts.pool.reopen()?;
+
+    msg!("Pool reopened: {:?}", ctx.accounts.mint_token_one.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReopenPool<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,27 @@
+use crate::{events::MultisigSet, state::*};
+use anchor_lang::prelude::*;
+
+// The admin key is cleared, so every admin instruction gated on it stops working
+// and changes go through create_proposal instead
+pub fn set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    ctx.accounts
+        .dex_configuration_account
+        .set_multisig(signers.clone(), threshold)?;
+
+    emit!(MultisigSet { signers, threshold });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::state::{CurveConfiguration, LiquidityPool, PauseState};
+
+pub fn set_pool_pause(ctx: Context<SetPoolPause>, pause: PauseState) -> Result<()> {
+    ctx.accounts.pool.pause = pause;
+
+    msg!(
+        "Pool pause: {:?} {:?}",
+        ctx.accounts.mint_token_one.key(),
+        pause
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPoolPause<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    pub admin: Signer<'info>,
+}
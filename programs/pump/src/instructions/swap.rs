@@ -4,16 +4,24 @@ use anchor_spl::{
     token::{Mint, Token, TokenAccount},
 };
 
-use crate::state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount};
+use crate::state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, TradeDirection};
 
-pub fn swap(ctx: Context<Swap>, amount: u64, style: u64) -> Result<()> {
+pub fn swap(
+    ctx: Context<Swap>,
+    amount: u64,
+    direction: TradeDirection,
+    min_amount_out: u64,
+) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
 
     let token_one_accounts = (
         &mut *ctx.accounts.mint_token_one.clone(),
         &mut *ctx.accounts.pool_token_account_one,
         &mut *ctx.accounts.user_token_account_one,
     );
+    let sol_vault = &ctx.accounts.sol_vault.to_account_info();
+    let treasury = &ctx.accounts.treasury.to_account_info();
 
     // let token_two_accounts = (
     //     &mut *ctx.accounts.mint_token_one.clone(),
@@ -22,11 +30,14 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64) -> Result<()> {
     // );
 
     pool.swap(
-        &*ctx.accounts.dex_configuration_account,
+        &ctx.accounts.dex_configuration_account,
         token_one_accounts,
         // token_two_accounts,
+        sol_vault,
+        treasury,
         amount,
-        style,
+        direction,
+        min_amount_out,
         &ctx.accounts.user,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
@@ -38,7 +49,6 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64) -> Result<()> {
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(
-        mut,
         seeds = [CurveConfiguration::SEED.as_bytes()],
         bump,
     )]
@@ -51,6 +61,17 @@ pub struct Swap<'info> {
     )]
     pub pool: Box<Account<'info, LiquidityPool>>,
 
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    // receives the fee, a funded wallet so that small fees clear its rent exemption
+    #[account(mut, address = dex_configuration_account.treasury)]
+    pub treasury: SystemAccount<'info>,
+
     #[account(mut)]
     pub mint_token_one: Box<Account<'info, Mint>>,
 
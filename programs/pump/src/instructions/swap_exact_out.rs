@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::Swap,
+    state::{LiquidityPoolAccount, TradeDirection},
+};
+
+pub fn swap_exact_out(
+    ctx: Context<Swap>,
+    amount_out: u64,
+    direction: TradeDirection,
+    max_amount_in: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.ensure_not_paused(&ctx.accounts.dex_configuration_account, false)?;
+
+    let token_one_accounts = (
+        &mut *ctx.accounts.mint_token_one.clone(),
+        &mut *ctx.accounts.pool_token_account_one,
+        &mut *ctx.accounts.user_token_account_one,
+    );
+    let sol_vault = &ctx.accounts.sol_vault.to_account_info();
+    let treasury = &ctx.accounts.treasury.to_account_info();
+
+    pool.swap_exact_out(
+        &ctx.accounts.dex_configuration_account,
+        token_one_accounts,
+        sol_vault,
+        treasury,
+        amount_out,
+        direction,
+        max_amount_in,
+        &ctx.accounts.user,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    Ok(())
+}
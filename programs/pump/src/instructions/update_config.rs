@@ -0,0 +1,62 @@
+use crate::{events::ConfigUpdateQueued, state::*};
+use anchor_lang::prelude::*;
+
+// Queues the new values, they only apply once execute_config_update runs after the delay
+#[allow(clippy::too_many_arguments)]
+pub fn update_config(
+    ctx: Context<UpdateCurveConfiguration>,
+    buy_fee_bps: u16,
+    sell_fee_bps: u16,
+    initial_virtual_sol_reserves: u64,
+    initial_virtual_token_reserves: u64,
+    completion_threshold: CompletionThreshold,
+    treasury: Pubkey,
+    raydium_amm_config: Pubkey,
+) -> Result<()> {
+    let pending_update = PendingUpdate::new(
+        ConfigUpdate {
+            buy_fee_bps,
+            sell_fee_bps,
+            initial_virtual_sol_reserves,
+            initial_virtual_token_reserves,
+            completion_threshold,
+            treasury,
+            raydium_amm_config,
+        },
+        &ctx.accounts.dex_configuration_account,
+        ctx.accounts.admin.key(),
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    emit!(ConfigUpdateQueued {
+        update: pending_update.update,
+        effective_at: pending_update.effective_at,
+    });
+
+    ctx.accounts.pending_update.set_inner(pending_update);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCurveConfiguration<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = PendingUpdate::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [PendingUpdate::SEED_PREFIX.as_bytes()],
+        bump
+    )]
+    pub pending_update: Box<Account<'info, PendingUpdate>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
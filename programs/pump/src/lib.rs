@@ -2,11 +2,16 @@ use anchor_lang::prelude::*;
 
 pub mod consts;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 use crate::instructions::*;
+use crate::state::{
+    AdminAction, CompletionThreshold, CurveType, LaunchMode, MigrationTarget, PauseState,
+    TradeDirection,
+};
 
 declare_id!("FLFGbCf9hiMZceaEp1P1KNzJJFQLyvQCPriMsBygUP1Y");
 
@@ -14,13 +19,145 @@ declare_id!("FLFGbCf9hiMZceaEp1P1KNzJJFQLyvQCPriMsBygUP1Y");
 pub mod pump {
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeCurveConfiguration>, fee: f64) -> Result<()> {
-        instructions::initialize(ctx, fee)
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        ctx: Context<InitializeCurveConfiguration>,
+        buy_fee_bps: u16,
+        sell_fee_bps: u16,
+        initial_virtual_sol_reserves: u64,
+        initial_virtual_token_reserves: u64,
+        completion_threshold: CompletionThreshold,
+        min_update_delay: i64,
+        raydium_amm_config: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize(
+            ctx,
+            buy_fee_bps,
+            sell_fee_bps,
+            initial_virtual_sol_reserves,
+            initial_virtual_token_reserves,
+            completion_threshold,
+            min_update_delay,
+            raydium_amm_config,
+        )
+    }
+
+    pub fn migrate_config(
+        ctx: Context<MigrateCurveConfiguration>,
+        min_update_delay: i64,
+    ) -> Result<()> {
+        instructions::migrate_config(ctx, min_update_delay)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_config(
+        ctx: Context<UpdateCurveConfiguration>,
+        buy_fee_bps: u16,
+        sell_fee_bps: u16,
+        initial_virtual_sol_reserves: u64,
+        initial_virtual_token_reserves: u64,
+        completion_threshold: CompletionThreshold,
+        treasury: Pubkey,
+        raydium_amm_config: Pubkey,
+    ) -> Result<()> {
+        instructions::update_config(
+            ctx,
+            buy_fee_bps,
+            sell_fee_bps,
+            initial_virtual_sol_reserves,
+            initial_virtual_token_reserves,
+            completion_threshold,
+            treasury,
+            raydium_amm_config,
+        )
+    }
+
+    pub fn execute_config_update(ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+        instructions::execute_config_update(ctx)
+    }
+
+    pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+        instructions::cancel_config_update(ctx)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        instructions::cancel_admin_proposal(ctx)
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, pause: PauseState) -> Result<()> {
+        instructions::set_pause(ctx, pause)
+    }
+
+    pub fn set_pool_pause(ctx: Context<SetPoolPause>, pause: PauseState) -> Result<()> {
+        instructions::set_pool_pause(ctx, pause)
+    }
+
+    pub fn set_multisig(
+        ctx: Context<SetMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig(ctx, signers, threshold)
     }
 
-    // pub fn create_pool(ctx: Context<CreateLiquidityPool>) -> Result<()> {
-    //     instructions::create_pool(ctx)
-    // }
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: AdminAction) -> Result<()> {
+        instructions::create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::close_proposal(ctx)
+    }
+
+    pub fn create_pool(
+        ctx: Context<CreateLiquidityPool>,
+        curve_type: CurveType,
+        curve_params: [u64; 3],
+        migration_target: MigrationTarget,
+        launch_mode: LaunchMode,
+    ) -> Result<()> {
+        instructions::create_pool(ctx, curve_type, curve_params, migration_target, launch_mode)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_token(
+        ctx: Context<CreateToken>,
+        name: String,
+        symbol: String,
+        uri: String,
+        curve_type: CurveType,
+        curve_params: [u64; 3],
+        migration_target: MigrationTarget,
+        launch_mode: LaunchMode,
+    ) -> Result<()> {
+        instructions::create_token(
+            ctx,
+            name,
+            symbol,
+            uri,
+            curve_type,
+            curve_params,
+            migration_target,
+            launch_mode,
+        )
+    }
 
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
@@ -34,7 +171,66 @@ pub mod pump {
         instructions::remove_liquidity(ctx, shares)
     }
 
-    pub fn swap(ctx: Context<Swap>, amount: u64, style: u64) -> Result<()> {
-        instructions::swap(ctx, amount, style)
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount: u64,
+        direction: TradeDirection,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::swap(ctx, amount, direction, min_amount_out)
+    }
+
+    pub fn swap_exact_out(
+        ctx: Context<Swap>,
+        amount_out: u64,
+        direction: TradeDirection,
+        max_amount_in: u64,
+    ) -> Result<()> {
+        instructions::swap_exact_out(ctx, amount_out, direction, max_amount_in)
+    }
+
+    pub fn migrate_to_raydium(ctx: Context<MigrateToRaydium>) -> Result<()> {
+        instructions::migrate_to_raydium(ctx)
+    }
+
+    pub fn migrate_to_meteora(ctx: Context<MigrateToMeteora>) -> Result<()> {
+        instructions::migrate_to_meteora(ctx)
+    }
+
+    pub fn reopen_pool(ctx: Context<ReopenPool>) -> Result<()> {
+        instructions::reopen_pool(ctx)
+    }
+
+    pub fn presale_deposit(
+        ctx: Context<PresaleDeposit>,
+        amount: u64,
+        max_allocation: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::presale_deposit(ctx, amount, max_allocation, proof)
+    }
+
+    pub fn launch(ctx: Context<Launch>) -> Result<()> {
+        instructions::launch(ctx)
+    }
+
+    pub fn presale_claim(ctx: Context<PresaleClaim>) -> Result<()> {
+        instructions::presale_claim(ctx)
+    }
+
+    pub fn presale_refund(ctx: Context<PresaleRefund>) -> Result<()> {
+        instructions::presale_refund(ctx)
+    }
+
+    pub fn auction_buy(ctx: Context<AuctionBuy>, amount: u64, max_sol_in: u64) -> Result<()> {
+        instructions::auction_buy(ctx, amount, max_sol_in)
+    }
+
+    pub fn place_order(ctx: Context<PlaceOrder>, lamports: u64) -> Result<()> {
+        instructions::place_order(ctx, lamports)
+    }
+
+    pub fn claim_order(ctx: Context<ClaimOrder>) -> Result<()> {
+        instructions::claim_order(ctx)
     }
 }
@@ -1,27 +1,612 @@
 use crate::errors::CustomError;
-use crate::utils::convert_from_float;
-use crate::utils::convert_to_float;
+use crate::utils::{
+    allowlist_leaf, calculate_amount_before_fee, calculate_fee, integer_sqrt,
+    sol_vault_minimum_balance, verify_proof, ConstantProductCurve, Curve, CurveState,
+    ExponentialCurve, LinearCurve, SigmoidCurve, PRICE_SCALE,
+};
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_lang::{system_program, Discriminator};
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use std::cmp;
-use std::ops::Div;
-use std::ops::Mul;
-use std::ops::Sub;
 
 #[account]
 pub struct CurveConfiguration {
-    pub fees: f64,
+    pub buy_fee_bps: u16,                    // Fee charged on buys, in basis points
+    pub sell_fee_bps: u16,                   // Fee charged on sells, in basis points
+    pub initial_virtual_sol_reserves: u64,   // Virtual SOL reserve new pools start with
+    pub initial_virtual_token_reserves: u64, // Virtual token reserve new pools start with
+    pub completion_threshold: CompletionThreshold, // Point at which a pool's curve completes
+    pub admin: Pubkey,                       // Only signer allowed to update the configuration
+    pub treasury: Pubkey,                    // Wallet protocol revenue is paid to
+    pub pending_admin: Option<Pubkey>,       // Proposed admin, in charge once it accepts
+    pub pause: PauseState,                   // Emergency stop for every pool
+    pub multisig_signers: Vec<Pubkey>,       // Keys that approve admin proposals
+    pub multisig_threshold: u8,              // Approvals a proposal needs, 0 without a multisig
+    pub multisig_epoch: u64,                 // Bumped whenever the signer set changes
+    pub proposal_count: u64,                 // Proposals created so far, the next one's id
+    pub min_update_delay: i64,               // Seconds a queued update waits before it applies
+    pub raydium_amm_config: Pubkey,          // Raydium fee tier completed pools migrate into
 }
 
 impl CurveConfiguration {
     pub const SEED: &'static str = "CurveConfiguration";
 
-    // Discriminator (8) + f64 (8)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 8;
+    pub const MAX_FEE_BPS: u16 = 1_000; // 10%
+
+    pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+    // Discriminator (8) + admin (32) + buy fee (2) + sell fee (2)
+    // + initial virtual SOL reserves (8) + initial virtual token reserves (8)
+    // + completion threshold (1 + 8) + treasury (32) + pending admin (1 + 32) + pause (1)
+    // + multisig signers (4 + MAX_MULTISIG_SIGNERS * 32) + multisig threshold (1)
+    // + multisig epoch (8) + proposal count (8) + min update delay (8)
+    // + Raydium AMM config (32)
+    pub const ACCOUNT_SIZE: usize = 8
+        + 32
+        + 2
+        + 2
+        + 8
+        + 8
+        + 1
+        + 8
+        + 32
+        + 1
+        + 32
+        + 1
+        + 4
+        + Self::MAX_MULTISIG_SIGNERS * 32
+        + 1
+        + 8
+        + 8
+        + 8
+        + 32;
+
+    // Discriminator (8) + f64 (8) + reserved (32), before fees were stored in basis points
+    pub const LEGACY_ACCOUNT_SIZE: usize = 8 + 32 + 8;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        buy_fee_bps: u16,
+        sell_fee_bps: u16,
+        initial_virtual_sol_reserves: u64,
+        initial_virtual_token_reserves: u64,
+        completion_threshold: CompletionThreshold,
+        admin: Pubkey,
+        treasury: Pubkey,
+        min_update_delay: i64,
+    ) -> Self {
+        Self {
+            buy_fee_bps,
+            sell_fee_bps,
+            initial_virtual_sol_reserves,
+            initial_virtual_token_reserves,
+            completion_threshold,
+            admin,
+            treasury,
+            pending_admin: None,
+            pause: PauseState::Unpaused,
+            multisig_signers: Vec::new(),
+            multisig_threshold: 0,
+            multisig_epoch: 0,
+            proposal_count: 0,
+            min_update_delay,
+            raydium_amm_config: Pubkey::default(),
+        }
+    }
+
+    pub fn update(&mut self, update: &ConfigUpdate) -> Result<()> {
+        update.validate()?;
+
+        // existing pools keep the virtual reserves they were created with
+        self.buy_fee_bps = update.buy_fee_bps;
+        self.sell_fee_bps = update.sell_fee_bps;
+        self.initial_virtual_sol_reserves = update.initial_virtual_sol_reserves;
+        self.initial_virtual_token_reserves = update.initial_virtual_token_reserves;
+        self.completion_threshold = update.completion_threshold;
+        self.treasury = update.treasury;
+        self.raydium_amm_config = update.raydium_amm_config;
+
+        Ok(())
+    }
+
+    // Hands admin rights to an M-of-N signer set. The single admin key is cleared,
+    // so from here on every admin action goes through an approved proposal.
+    pub fn set_multisig(&mut self, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        let mut unique = signers.clone();
+        unique.sort();
+        unique.dedup();
+
+        if threshold == 0
+            || threshold as usize > signers.len()
+            || signers.len() > Self::MAX_MULTISIG_SIGNERS
+            || unique.len() != signers.len()
+        {
+            return err!(CustomError::InvalidMultisig);
+        }
+
+        self.admin = Pubkey::default();
+        self.pending_admin = None;
+        self.multisig_signers = signers;
+        self.multisig_threshold = threshold;
+        self.multisig_epoch += 1;
+
+        Ok(())
+    }
+
+    // Back to a single admin key, dropping the signer set
+    pub fn set_admin(&mut self, admin: Pubkey) {
+        self.admin = admin;
+        self.pending_admin = None;
+        if self.multisig_threshold > 0 {
+            self.multisig_signers.clear();
+            self.multisig_threshold = 0;
+            self.multisig_epoch += 1;
+        }
+    }
+
+    pub fn ensure_multisig_signer(&self, signer: &Pubkey) -> Result<()> {
+        if self.multisig_threshold == 0 {
+            return err!(CustomError::MultisigNotEnabled);
+        }
+
+        if !self.multisig_signers.contains(signer) {
+            return err!(CustomError::NotMultisigSigner);
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_fees(buy_fee_bps: u16, sell_fee_bps: u16) -> Result<()> {
+        if buy_fee_bps > Self::MAX_FEE_BPS || sell_fee_bps > Self::MAX_FEE_BPS {
+            return err!(CustomError::InvalidFee);
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_update_delay(min_update_delay: i64) -> Result<()> {
+        if min_update_delay < 0 {
+            return err!(CustomError::InvalidUpdateDelay);
+        }
 
-    pub fn new(fees: f64) -> Self {
-        Self { fees }
+        Ok(())
+    }
+
+    // Rebuilds an account written with the legacy f64 percentage fee, charging the
+    // same rate on both buys and sells
+    pub fn from_legacy(data: &[u8], admin: Pubkey, min_update_delay: i64) -> Result<Self> {
+        if data.len() != Self::LEGACY_ACCOUNT_SIZE || data[..8] != Self::DISCRIMINATOR {
+            return err!(CustomError::InvalidConfigurationAccount);
+        }
+
+        let mut fees = [0u8; 8];
+        fees.copy_from_slice(&data[8..16]);
+        let fee_bps = Self::legacy_fee_bps(f64::from_le_bytes(fees))?;
+
+        // legacy configurations priced pools on real reserves only and never completed,
+        // Raydium migrations wait until the admin picks a fee tier. Fees go to the admin
+        // until it points the treasury elsewhere.
+        Ok(Self::new(
+            fee_bps,
+            fee_bps,
+            0,
+            0,
+            CompletionThreshold::Disabled,
+            admin,
+            admin,
+            min_update_delay,
+        ))
+    }
+
+    // Percentage => basis points, rounded to the nearest. Legacy fees went up to 100%,
+    // anything above the current maximum is capped so the configuration stays usable.
+    pub fn legacy_fee_bps(legacy_fees: f64) -> Result<u16> {
+        if !(0_f64..=100_f64).contains(&legacy_fees) {
+            return err!(CustomError::InvalidFee);
+        }
+
+        let fee_bps = (legacy_fees * 100_f64).round() as u16;
+
+        Ok(cmp::min(fee_bps, Self::MAX_FEE_BPS))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigUpdate {
+    pub buy_fee_bps: u16,                    // Fee charged on buys, in basis points
+    pub sell_fee_bps: u16,                   // Fee charged on sells, in basis points
+    pub initial_virtual_sol_reserves: u64,   // Virtual SOL reserve new pools start with
+    pub initial_virtual_token_reserves: u64, // Virtual token reserve new pools start with
+    pub completion_threshold: CompletionThreshold, // Point at which a pool's curve completes
+    pub treasury: Pubkey,                    // Wallet protocol revenue is paid to
+    pub raydium_amm_config: Pubkey,          // Raydium fee tier completed pools migrate into
+}
+
+impl ConfigUpdate {
+    // u16 (2) * 2 + u64 (8) * 2 + completion threshold (1 + 8) + treasury (32)
+    // + Raydium AMM config (32)
+    pub const SIZE: usize = 2 * 2 + 8 * 2 + 1 + 8 + 32 + 32;
+
+    pub fn validate(&self) -> Result<()> {
+        CurveConfiguration::validate_fees(self.buy_fee_bps, self.sell_fee_bps)?;
+        self.completion_threshold.validate()
+    }
+}
+
+// Configuration changes wait here until their delay passes, so traders see them coming.
+// There is a single slot, an update has to execute or be cancelled before the next one queues.
+#[account]
+pub struct PendingUpdate {
+    pub update: ConfigUpdate, // Values the configuration takes on execution
+    pub effective_at: i64,    // Unix timestamp from which anyone can execute
+    pub payer: Pubkey,        // Wallet that funded the account, refunded on close
+}
+
+impl PendingUpdate {
+    pub const SEED_PREFIX: &'static str = "pending_update";
+
+    // Discriminator (8) + update (ConfigUpdate::SIZE) + effective at (8) + payer (32)
+    pub const ACCOUNT_SIZE: usize = 8 + ConfigUpdate::SIZE + 8 + 32;
+
+    pub fn new(
+        update: ConfigUpdate,
+        config: &CurveConfiguration,
+        payer: Pubkey,
+        now: i64,
+    ) -> Result<Self> {
+        update.validate()?;
+
+        Ok(Self {
+            update,
+            effective_at: now
+                .checked_add(config.min_update_delay)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?,
+            payer,
+        })
+    }
+
+    pub fn ensure_effective(&self, now: i64) -> Result<()> {
+        if now < self.effective_at {
+            return err!(CustomError::UpdateNotEffective);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    // queued like a single admin's update, the pending update account is passed on execution
+    UpdateConfig { update: ConfigUpdate },
+    // the pending update and its payer are passed as the first two remaining accounts
+    CancelConfigUpdate,
+    SetPause { pause: PauseState },
+    // the pool is passed as the first remaining account on execution
+    SetPoolPause { pool: Pubkey, pause: PauseState },
+    // the pool is passed as the first remaining account on execution
+    ReopenPool { pool: Pubkey },
+    SetMultisig { signers: Vec<Pubkey>, threshold: u8 },
+    // proposes a single key, which takes over once it calls accept_admin
+    SetAdmin { admin: Pubkey },
+}
+
+impl AdminAction {
+    // Variant (1) + the largest variant, SetMultisig (4 + MAX_MULTISIG_SIGNERS * 32 + 1)
+    pub const MAX_SIZE: usize = 1 + 4 + CurveConfiguration::MAX_MULTISIG_SIGNERS * 32 + 1;
+}
+
+#[account]
+pub struct Proposal {
+    pub id: u64,                // Position in the configuration's proposal count
+    pub proposer: Pubkey,       // Signer that created the proposal, refunded on close
+    pub action: AdminAction,    // Admin action run on execution
+    pub approvals: Vec<Pubkey>, // Signers that approved, the proposer included
+    pub executed: bool,         // Set once the action ran
+    pub epoch: u64,             // Signer set the proposal was created under
+    pub expires_at: i64,        // Unix timestamp after which it can no longer run
+}
+
+impl Proposal {
+    pub const SEED_PREFIX: &'static str = "proposal";
+
+    pub const LIFETIME: i64 = 7 * 24 * 60 * 60; // 7 days
+
+    // Discriminator (8) + id (8) + proposer (32) + action (AdminAction::MAX_SIZE)
+    // + approvals (4 + MAX_MULTISIG_SIGNERS * 32) + executed (1) + epoch (8) + expires at (8)
+    pub const ACCOUNT_SIZE: usize = 8
+        + 8
+        + 32
+        + AdminAction::MAX_SIZE
+        + 4
+        + CurveConfiguration::MAX_MULTISIG_SIGNERS * 32
+        + 1
+        + 8
+        + 8;
+
+    pub fn new(
+        id: u64,
+        proposer: Pubkey,
+        action: AdminAction,
+        config: &CurveConfiguration,
+        now: i64,
+    ) -> Result<Self> {
+        Ok(Self {
+            id,
+            proposer,
+            action,
+            // creating a proposal counts as the proposer's approval
+            approvals: vec![proposer],
+            executed: false,
+            epoch: config.multisig_epoch,
+            expires_at: now
+                .checked_add(Self::LIFETIME)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?,
+        })
+    }
+
+    // A proposal stops running once executed, once it expires, or once the signer set
+    // changes. Each signer approves at most once per set, so approvals stay bounded.
+    pub fn ensure_open(&self, config: &CurveConfiguration, now: i64) -> Result<()> {
+        if self.executed {
+            return err!(CustomError::ProposalAlreadyExecuted);
+        }
+
+        if self.epoch != config.multisig_epoch {
+            return err!(CustomError::ProposalStale);
+        }
+
+        if now >= self.expires_at {
+            return err!(CustomError::ProposalExpired);
+        }
+
+        Ok(())
+    }
+
+    pub fn approve(&mut self, config: &CurveConfiguration, signer: Pubkey, now: i64) -> Result<()> {
+        self.ensure_open(config, now)?;
+
+        if self.approvals.contains(&signer) {
+            return err!(CustomError::AlreadyApproved);
+        }
+        self.approvals.push(signer);
+
+        Ok(())
+    }
+
+    pub fn ensure_approved(&self, config: &CurveConfiguration, now: i64) -> Result<()> {
+        self.ensure_open(config, now)?;
+
+        if config.multisig_threshold == 0
+            || self.approvals.len() < config.multisig_threshold as usize
+        {
+            return err!(CustomError::NotEnoughApprovals);
+        }
+
+        Ok(())
+    }
+
+    // Closed proposals hand their rent back to the proposer
+    pub fn ensure_closable(&self, config: &CurveConfiguration, now: i64) -> Result<()> {
+        match self.ensure_open(config, now) {
+            Ok(()) => err!(CustomError::ProposalStillOpen),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionThreshold {
+    Disabled,                    // Pools trade forever
+    SolRaised { lamports: u64 }, // Completes once the pool holds this much real SOL
+    TokensSold { amount: u64 },  // Completes once this many tokens were bought out of the pool
+}
+
+impl CompletionThreshold {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            CompletionThreshold::SolRaised { lamports: 0 }
+            | CompletionThreshold::TokensSold { amount: 0 } => {
+                err!(CustomError::InvalidCompletionThreshold)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PauseState {
+    Unpaused,        // Business as usual
+    Paused,          // Trading and liquidity changes are stopped
+    WithdrawalsOnly, // Only liquidity removals, claims and refunds go through
+}
+
+impl PauseState {
+    pub fn ensure_allows(&self, withdrawal: bool) -> Result<()> {
+        match self {
+            PauseState::Unpaused => Ok(()),
+            PauseState::WithdrawalsOnly if withdrawal => Ok(()),
+            _ => err!(CustomError::Paused),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolStatus {
+    Active,    // Trading along the curve
+    Completed, // Curve completed, waiting to be migrated
+    Migrated,  // Liquidity moved to an external AMM
+    Presale,   // Taking presale bids, trading opens at launch
+    Cancelled, // Presale or batch auction could not launch, bids are refunded
+    Auction,   // Selling a tranche by Dutch auction, the curve opens once it settles
+    Batching,  // Collecting opening orders, cleared at one price before trading opens
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchMode {
+    Instant, // Trading opens as soon as the pool is created
+    Presale {
+        start_slot: u64,                  // First slot bids are accepted
+        end_slot: u64,                    // Last slot bids are accepted
+        soft_cap: u64,                    // Lamports the presale must raise to launch
+        hard_cap: u64,                    // Most lamports spent at launch, the rest is refunded
+        allowlist_root: Option<[u8; 32]>, // Merkle root of allowed wallets and their caps
+    },
+    DutchAuction {
+        start_slot: u64,  // Slot the price starts decaying from
+        end_slot: u64,    // Slot the price reaches the floor and the auction ends
+        start_price: u64, // Opening price, in lamports per PRICE_SCALE raw token units
+        floor_price: u64, // Closing price, in the same units
+        tranche: u64,     // Tokens for sale before the curve opens
+    },
+    BatchAuction {
+        slots: u64, // Slots after creation that buy orders are collected for
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Presale {
+    pub start_slot: u64,                  // First slot bids are accepted
+    pub end_slot: u64,                    // Last slot bids are accepted
+    pub soft_cap: u64,                    // Lamports the presale must raise to launch
+    pub hard_cap: u64,                    // Most lamports spent at launch, the rest is refunded
+    pub total_deposits: u64,              // Lamports bid so far
+    pub tokens_allocated: u64,            // Tokens set aside for bidders at launch
+    pub accepted_deposits: u64,           // Lamports spent at launch, capped at the hard cap
+    pub deposits_claimed: u64,            // Lamports of bids settled by claims
+    pub tokens_claimed: u64,              // Tokens paid out by claims
+    pub refunds_paid: u64,                // Oversubscribed lamports paid back by claims
+    pub allowlist_root: Option<[u8; 32]>, // Merkle root of allowed wallets and their caps
+}
+
+impl Presale {
+    // 10 * u64 (8) + allowlist root (1 + 32)
+    pub const SIZE: usize = 10 * 8 + 1 + 32;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DutchAuction {
+    pub start_slot: u64,  // Slot the price starts decaying from
+    pub end_slot: u64,    // Slot the price reaches the floor and the auction ends
+    pub start_price: u64, // Opening price, in lamports per PRICE_SCALE raw token units
+    pub floor_price: u64, // Closing price, in the same units
+    pub tranche: u64,     // Tokens for sale before the curve opens
+    pub tokens_sold: u64, // Tranche tokens bought so far
+    pub sol_raised: u64,  // Lamports paid for them
+}
+
+impl DutchAuction {
+    // 7 * u64 (8)
+    pub const SIZE: usize = 7 * 8;
+
+    // Decays linearly from the start price to the floor, rounded up
+    pub fn price_at(&self, slot: u64) -> u64 {
+        if slot <= self.start_slot {
+            return self.start_price;
+        }
+        if slot >= self.end_slot {
+            return self.floor_price;
+        }
+
+        let decay = (self.start_price - self.floor_price) as u128
+            * (slot - self.start_slot) as u128
+            / (self.end_slot - self.start_slot) as u128;
+
+        self.start_price - decay as u64
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BatchAuction {
+    pub end_slot: u64,        // Last slot orders are collected
+    pub total_orders: u64,    // Lamports ordered so far
+    pub tokens_cleared: u64,  // Tokens the orders bought at the clearing price
+    pub accepted_orders: u64, // Lamports spent at the clearing, the rest is refunded
+    pub orders_claimed: u64,  // Lamports of orders settled by claims
+    pub tokens_claimed: u64,  // Tokens paid out by claims
+    pub refunds_paid: u64,    // Unspent lamports paid back by claims
+}
+
+impl BatchAuction {
+    // 7 * u64 (8)
+    pub const SIZE: usize = 7 * 8;
+}
+
+// The opening orders are kept one account per wallet rather than in a single order
+// book account. A shared book would cap the number of orders at its size and make
+// every order in the window write to the same account, and the uniform clearing
+// only needs the total in BatchAuction, so each wallet's order lives on its own.
+#[account]
+pub struct BatchOrder {
+    pub lamports: u64, // SOL this wallet ordered with, repeat orders are added up
+}
+
+impl BatchOrder {
+    pub const SEED_PREFIX: &'static str = "batch_order";
+
+    // Discriminator (8) + lamports (8)
+    pub const ACCOUNT_SIZE: usize = 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MigrationTarget {
+    Raydium, // Raydium CPMM pool
+    Meteora, // Meteora dynamic AMM pool
+}
+
+#[derive(AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TradeDirection {
+    Buy,  // SOL in, token out
+    Sell, // Token in, SOL out
+}
+
+impl TryFrom<u8> for TradeDirection {
+    type Error = CustomError;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TradeDirection::Buy),
+            1 => Ok(TradeDirection::Sell),
+            _ => Err(CustomError::InvalidTradeDirection),
+        }
+    }
+}
+
+// Decoded by hand so unknown directions are reported as InvalidTradeDirection
+// instead of silently mapping to a trade
+impl AnchorDeserialize for TradeDirection {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let value = u8::deserialize_reader(reader)?;
+
+        TradeDirection::try_from(value).map_err(|error| {
+            let error: anchor_lang::error::Error = error.into();
+            error.log();
+            std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string())
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveType {
+    ConstantProduct, // x * y = k on virtual + real reserves, params unused
+    Linear,          // params: [base price, slope, unused]
+    Exponential,     // params: [base price, growth rate, unused]
+    Sigmoid,         // params: [max price, growth rate, midpoint supply]
+}
+
+impl CurveType {
+    pub fn validate_params(&self, params: &[u64; 3]) -> Result<()> {
+        let valid = match self {
+            CurveType::ConstantProduct => true,
+            // a zero base price would give the first tokens away for free
+            CurveType::Linear => params[0] > 0,
+            CurveType::Exponential | CurveType::Sigmoid => params[0] > 0 && params[1] > 0,
+        };
+
+        if !valid {
+            return err!(CustomError::InvalidCurveParameters);
+        }
+
+        Ok(())
     }
 }
 
@@ -37,22 +622,74 @@ impl LiquidityProvider {
     pub const ACCOUNT_SIZE: usize = 8 + 8;
 }
 
+#[account]
+pub struct PresaleBid {
+    pub amount: u64, // Lamports this bidder deposited during the presale
+}
+
+impl PresaleBid {
+    pub const SEED_PREFIX: &'static str = "presale_bid";
+
+    // Discriminator (8) + amount (8)
+    pub const ACCOUNT_SIZE: usize = 8 + 8;
+}
+
 #[account]
 pub struct LiquidityPool {
     pub token_one: Pubkey, // Public key of the first token in the liquidity pool
     // pub token_two: Pubkey, // Public key of the second token in the pool, currently is sol native coin
-    pub total_supply: u64, // Total supply of liquidity tokens
-    pub reserve_one: u64,  // Reserve amount of token_one in the pool
-    pub reserve_two: u64,  // Reserve amount of token_two in the pool
-    pub bump: u8,          // Nonce for the program-derived address
+    pub total_supply: u64,           // Total supply of liquidity tokens
+    pub reserve_one: u64,            // Reserve amount of token_one in the pool
+    pub reserve_two: u64,            // Reserve amount of token_two in the pool
+    pub bump: u8,                    // Nonce for the program-derived address
+    pub sol_vault_bump: u8,          // Nonce for the SOL vault holding reserve_two
+    pub virtual_sol_reserves: u64,   // Virtual SOL added on top of reserve_two when pricing
+    pub virtual_token_reserves: u64, // Virtual tokens added on top of reserve_one when pricing
+    pub curve_type: CurveType,       // Price curve swaps are quoted on
+    pub curve_params: [u64; 3],      // Parameters of the price curve, see CurveType
+    pub tokens_sold: u64,            // Tokens bought out of the pool net of sells
+    pub status: PoolStatus,          // Lifecycle of the pool
+    // AMM the launcher chose to migrate to once the curve completes
+    pub migration_target: MigrationTarget,
+    // Presale window and totals, zeroed for pools launched instantly
+    pub presale: Presale,
+    // Dutch auction schedule and totals, zeroed for pools launched without one
+    pub auction: DutchAuction,
+    // Opening batch window and totals, zeroed for pools launched without one
+    pub batch_auction: BatchAuction,
+    // Emergency stop for this pool alone, on top of the global one
+    pub pause: PauseState,
 }
 
 impl LiquidityPool {
     pub const POOL_SEED_PREFIX: &'static str = "liquidity_pool";
+    pub const SOL_VAULT_SEED_PREFIX: &'static str = "sol_vault";
 
     // Discriminator (8) + Pubkey (32) + Pubkey (32) + totalsupply (8)
-    // + reserve one (8) + reserve two (8) + Bump (1)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+    // + reserve one (8) + reserve two (8) + Bump (1) + SOL vault bump (1)
+    // + virtual SOL reserves (8) + virtual token reserves (8)
+    // + curve type (1) + curve params (3 * 8) + tokens sold (8) + status (1)
+    // + migration target (1) + presale (Presale::SIZE) + auction (DutchAuction::SIZE)
+    // + batch auction (BatchAuction::SIZE) + pause (1)
+    pub const ACCOUNT_SIZE: usize = 8
+        + 32
+        + 32
+        + 8
+        + 8
+        + 8
+        + 1
+        + 1
+        + 8
+        + 8
+        + 1
+        + 3 * 8
+        + 8
+        + 1
+        + 1
+        + Presale::SIZE
+        + DutchAuction::SIZE
+        + BatchAuction::SIZE
+        + 1;
 
     // Helper function to generate a seed for PDAs based on token public keys
     // pub fn generate_seed(token_one: Pubkey, token_two: Pubkey) -> String {
@@ -64,7 +701,15 @@ impl LiquidityPool {
     // }
 
     // Constructor to initialize a LiquidityPool with two tokens and a bump for the PDA
-    pub fn new(token_one: Pubkey, bump: u8) -> Self {
+    pub fn new(
+        token_one: Pubkey,
+        bump: u8,
+        sol_vault_bump: u8,
+        configuration: &CurveConfiguration,
+        curve_type: CurveType,
+        curve_params: [u64; 3],
+        migration_target: MigrationTarget,
+    ) -> Self {
         Self {
             token_one,
             // token_two: token_one,
@@ -72,7 +717,679 @@ impl LiquidityPool {
             reserve_one: 0_u64,
             reserve_two: 0_u64,
             bump,
+            sol_vault_bump,
+            virtual_sol_reserves: configuration.initial_virtual_sol_reserves,
+            virtual_token_reserves: configuration.initial_virtual_token_reserves,
+            curve_type,
+            curve_params,
+            tokens_sold: 0_u64,
+            status: PoolStatus::Active,
+            migration_target,
+            presale: Presale::default(),
+            auction: DutchAuction::default(),
+            batch_auction: BatchAuction::default(),
+            pause: PauseState::Unpaused,
+        }
+    }
+
+    // Opens the pool for trading or holds it in a presale until launch
+    pub fn set_launch_mode(&mut self, launch_mode: LaunchMode) -> Result<()> {
+        match launch_mode {
+            LaunchMode::Instant => Ok(()),
+            LaunchMode::Presale {
+                start_slot,
+                end_slot,
+                soft_cap,
+                hard_cap,
+                allowlist_root,
+            } => {
+                if start_slot > end_slot
+                    || end_slot < Clock::get()?.slot
+                    || soft_cap == 0
+                    || soft_cap > hard_cap
+                {
+                    return err!(CustomError::InvalidPresaleParameters);
+                }
+
+                self.status = PoolStatus::Presale;
+                self.presale = Presale {
+                    start_slot,
+                    end_slot,
+                    soft_cap,
+                    hard_cap,
+                    allowlist_root,
+                    ..Presale::default()
+                };
+
+                Ok(())
+            }
+            LaunchMode::DutchAuction {
+                start_slot,
+                end_slot,
+                start_price,
+                floor_price,
+                tranche,
+            } => {
+                if start_slot >= end_slot
+                    || end_slot < Clock::get()?.slot
+                    || floor_price == 0
+                    || floor_price > start_price
+                    || tranche == 0
+                {
+                    return err!(CustomError::InvalidAuctionParameters);
+                }
+
+                // the tranche joins the curve's supply, so selling it back along a
+                // supply curve pays out what the curve charges for it. At the floor
+                // the auction has to raise at least that much.
+                if self.curve_type != CurveType::ConstantProduct {
+                    let curve_cost = self.curve().amount_in(
+                        &self.curve_state()?,
+                        tranche,
+                        TradeDirection::Buy,
+                    )?;
+                    let floor_cost = (tranche as u128) * (floor_price as u128) / PRICE_SCALE;
+                    if floor_cost < curve_cost as u128 {
+                        return err!(CustomError::InvalidAuctionParameters);
+                    }
+                }
+
+                self.status = PoolStatus::Auction;
+                self.auction = DutchAuction {
+                    start_slot,
+                    end_slot,
+                    start_price,
+                    floor_price,
+                    tranche,
+                    ..DutchAuction::default()
+                };
+
+                Ok(())
+            }
+            LaunchMode::BatchAuction { slots } => {
+                if slots == 0 {
+                    return err!(CustomError::InvalidBatchAuctionParameters);
+                }
+
+                self.status = PoolStatus::Batching;
+                self.batch_auction = BatchAuction {
+                    end_slot: Clock::get()?
+                        .slot
+                        .checked_add(slots)
+                        .ok_or(CustomError::OverflowOrUnderflowOccurred)?,
+                    ..BatchAuction::default()
+                };
+
+                Ok(())
+            }
+        }
+    }
+
+    // Books a buy order made in the opening batch window
+    pub fn record_batch_order(&mut self, lamports: u64, slot: u64) -> Result<()> {
+        if self.status != PoolStatus::Batching || slot > self.batch_auction.end_slot {
+            return err!(CustomError::BatchAuctionNotOpen);
+        }
+
+        if lamports == 0 {
+            return err!(CustomError::InvalidAmount);
+        }
+
+        self.batch_auction.total_orders = self
+            .batch_auction
+            .total_orders
+            .checked_add(lamports)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        Ok(())
+    }
+
+    // Fills the orders as one buy on the curve once the window closed, so all of
+    // them pay the same price and fee a single swap would. The fill stops at the
+    // completion threshold and at the tokens the pool holds, and the unspent SOL
+    // is refunded pro rata. Tokens stay in the pool until claimed, and normal
+    // trading opens. A batch that cannot fill at all is cancelled and refunded.
+    // Returns the fee owed to the treasury.
+    pub fn clear_batch_auction(
+        &mut self,
+        threshold: CompletionThreshold,
+        buy_fee_bps: u16,
+        slot: u64,
+    ) -> Result<u64> {
+        if self.status != PoolStatus::Batching {
+            return err!(CustomError::BatchAuctionNotOpen);
+        }
+
+        if slot <= self.batch_auction.end_slot {
+            return err!(CustomError::BatchAuctionNotEnded);
+        }
+
+        let orders = self.batch_auction.total_orders;
+        let (accepted, tokens) = self.quote_opening_buy(threshold, buy_fee_bps, orders)?;
+        if orders > 0 && tokens == 0 {
+            msg!("Batch auction cancelled: {:?}", self.token_one);
+            self.status = PoolStatus::Cancelled;
+            return Ok(0);
+        }
+
+        let fee = calculate_fee(accepted, buy_fee_bps as u64)?;
+        self.reserve_one = self
+            .reserve_one
+            .checked_sub(tokens)
+            .ok_or(CustomError::InsufficientFunds)?;
+        self.reserve_two = self
+            .reserve_two
+            .checked_add(accepted - fee)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.tokens_sold = self
+            .tokens_sold
+            .checked_add(tokens)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        self.batch_auction.tokens_cleared = tokens;
+        self.batch_auction.accepted_orders = accepted;
+        self.status = PoolStatus::Active;
+        msg!(
+            "Batch auction cleared: {:?} of {:?} lamports for {:?} tokens",
+            accepted,
+            orders,
+            tokens
+        );
+        self.update_status(threshold);
+
+        Ok(fee)
+    }
+
+    // Settles an order into its share of the cleared tokens and of the unspent SOL,
+    // both rounded down. A cancelled batch refunds orders in full. The last claim
+    // routes the rounding dust into the reserves.
+    pub fn claim_batch_order(&mut self, lamports: u64) -> Result<(u64, u64)> {
+        if self.status == PoolStatus::Batching {
+            return err!(CustomError::BatchAuctionNotEnded);
+        }
+
+        let total_orders = self.batch_auction.total_orders;
+        let unspent = total_orders - self.batch_auction.accepted_orders;
+        let pro_rata = |amount: u64| -> Result<u64> {
+            let share = (lamports as u128)
+                .checked_mul(amount as u128)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?
+                / total_orders as u128;
+            Ok(share as u64)
+        };
+        let tokens = pro_rata(self.batch_auction.tokens_cleared)?;
+        let refund = pro_rata(unspent)?;
+
+        self.batch_auction.orders_claimed = self
+            .batch_auction
+            .orders_claimed
+            .checked_add(lamports)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.batch_auction.tokens_claimed += tokens;
+        self.batch_auction.refunds_paid += refund;
+
+        if self.batch_auction.orders_claimed == total_orders {
+            let token_dust = self.batch_auction.tokens_cleared - self.batch_auction.tokens_claimed;
+            let sol_dust = unspent - self.batch_auction.refunds_paid;
+            self.reserve_one = self
+                .reserve_one
+                .checked_add(token_dust)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+            self.reserve_two = self
+                .reserve_two
+                .checked_add(sol_dust)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+            msg!(
+                "Batch auction settled: {:?} tokens and {:?} lamports of dust to the pool",
+                token_dust,
+                sol_dust
+            );
+        }
+
+        Ok((tokens, refund))
+    }
+
+    // Sells up to `amount` tranche tokens at the current auction price, returning the
+    // tokens bought and the lamports owed, rounded up. Proceeds join the reserves right
+    // away, and the auction settles once the tranche sells out.
+    pub fn record_auction_buy(
+        &mut self,
+        threshold: CompletionThreshold,
+        amount: u64,
+        slot: u64,
+    ) -> Result<(u64, u64)> {
+        if self.status != PoolStatus::Auction
+            || slot < self.auction.start_slot
+            || slot > self.auction.end_slot
+        {
+            return err!(CustomError::AuctionNotOpen);
+        }
+
+        let tokens = cmp::min(amount, self.auction.tranche - self.auction.tokens_sold);
+        if tokens == 0 {
+            return err!(CustomError::InvalidAmount);
+        }
+
+        let cost = (tokens as u128)
+            .checked_mul(self.auction.price_at(slot) as u128)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?
+            .div_ceil(PRICE_SCALE);
+        let cost = u64::try_from(cost).map_err(|_| CustomError::OverflowOrUnderflowOccurred)?;
+
+        // tranche sales count as curve supply, the floor price keeps their SOL at or
+        // above what a supply curve pays out when they are sold back
+        self.reserve_one = self
+            .reserve_one
+            .checked_sub(tokens)
+            .ok_or(CustomError::InsufficientFunds)?;
+        self.reserve_two = self
+            .reserve_two
+            .checked_add(cost)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.tokens_sold = self
+            .tokens_sold
+            .checked_add(tokens)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.auction.tokens_sold += tokens;
+        self.auction.sol_raised = self
+            .auction
+            .sol_raised
+            .checked_add(cost)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        if self.auction.tokens_sold == self.auction.tranche {
+            self.settle_auction(threshold);
+        }
+
+        Ok((tokens, cost))
+    }
+
+    // Opens the curve on what the auction left in the pool once its window ended
+    pub fn end_auction(&mut self, threshold: CompletionThreshold, slot: u64) -> Result<()> {
+        if self.status != PoolStatus::Auction {
+            return err!(CustomError::AuctionNotOpen);
+        }
+
+        if slot <= self.auction.end_slot {
+            return err!(CustomError::AuctionNotEnded);
+        }
+
+        self.settle_auction(threshold);
+
+        Ok(())
+    }
+
+    fn settle_auction(&mut self, threshold: CompletionThreshold) {
+        self.status = PoolStatus::Active;
+        msg!(
+            "Auction settled: {:?} tokens for {:?} lamports",
+            self.auction.tokens_sold,
+            self.auction.sol_raised
+        );
+        self.update_status(threshold);
+    }
+
+    // Books a bid made in the presale window
+    pub fn record_presale_deposit(&mut self, amount: u64, slot: u64) -> Result<()> {
+        if self.status != PoolStatus::Presale
+            || slot < self.presale.start_slot
+            || slot > self.presale.end_slot
+        {
+            return err!(CustomError::PresaleNotOpen);
+        }
+
+        if amount == 0 {
+            return err!(CustomError::InvalidAmount);
+        }
+
+        // bids past the hard cap are taken and settled pro rata at launch
+        self.presale.total_deposits = self
+            .presale
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        Ok(())
+    }
+
+    // On an allowlisted presale, checks the wallet's proof and keeps its bids within its cap
+    pub fn check_allowlist(
+        &self,
+        wallet: &Pubkey,
+        bid_total: u64,
+        max_allocation: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<()> {
+        let Some(root) = self.presale.allowlist_root else {
+            return Ok(());
+        };
+
+        if !verify_proof(proof, root, allowlist_leaf(wallet, max_allocation)) {
+            return err!(CustomError::NotAllowlisted);
+        }
+
+        if bid_total > max_allocation {
+            return err!(CustomError::PresaleAllocationExceeded);
+        }
+
+        Ok(())
+    }
+
+    // Settles the presale as the curve's opening buy and opens public trading.
+    // Up to the hard cap of the deposits is spent, charged the fee a swap would pay,
+    // and bidders share the bought tokens and the unspent SOL in proportion to their
+    // deposits, so all of them pay the same price. Both stay in the pool until claimed.
+    // A presale that cannot launch is cancelled instead, so bids can be refunded.
+    // Returns the fee owed to the treasury.
+    pub fn launch_presale(
+        &mut self,
+        threshold: CompletionThreshold,
+        buy_fee_bps: u16,
+        slot: u64,
+    ) -> Result<u64> {
+        if self.status != PoolStatus::Presale {
+            return err!(CustomError::PresaleNotOpen);
         }
+
+        if slot <= self.presale.end_slot {
+            return err!(CustomError::PresaleNotEnded);
+        }
+
+        let total_deposits = self.presale.total_deposits;
+        let (deposits, tokens) = if total_deposits < self.presale.soft_cap {
+            (0, 0)
+        } else {
+            let bid = cmp::min(total_deposits, self.presale.hard_cap);
+            self.quote_opening_buy(threshold, buy_fee_bps, bid)?
+        };
+
+        // below the soft cap, or with nothing left to fill, bidders get their SOL back
+        if total_deposits < self.presale.soft_cap || (total_deposits > 0 && tokens == 0) {
+            msg!("Presale cancelled: {:?}", self.token_one);
+            self.status = PoolStatus::Cancelled;
+            return Ok(0);
+        }
+
+        let fee = calculate_fee(deposits, buy_fee_bps as u64)?;
+        self.reserve_one = self
+            .reserve_one
+            .checked_sub(tokens)
+            .ok_or(CustomError::InsufficientFunds)?;
+        self.reserve_two = self
+            .reserve_two
+            .checked_add(deposits - fee)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.tokens_sold = self
+            .tokens_sold
+            .checked_add(tokens)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        self.presale.tokens_allocated = tokens;
+        self.presale.accepted_deposits = deposits;
+        self.status = PoolStatus::Active;
+        msg!(
+            "Presale launched: {:?} of {:?} lamports for {:?} tokens",
+            deposits,
+            total_deposits,
+            tokens
+        );
+        self.update_status(threshold);
+
+        Ok(fee)
+    }
+
+    // Releases a bid once the presale is cancelled, or once the window closed below
+    // the soft cap, cancelling the pool on the first refund
+    pub fn record_presale_refund(&mut self, amount: u64, slot: u64) -> Result<()> {
+        if self.status == PoolStatus::Presale
+            && slot > self.presale.end_slot
+            && self.presale.total_deposits < self.presale.soft_cap
+        {
+            msg!("Presale cancelled: {:?}", self.token_one);
+            self.status = PoolStatus::Cancelled;
+        }
+
+        if self.status != PoolStatus::Cancelled {
+            return err!(CustomError::PresaleNotRefundable);
+        }
+
+        self.presale.total_deposits = self
+            .presale
+            .total_deposits
+            .checked_sub(amount)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        Ok(())
+    }
+
+    // Settles a bid into its share of the presale tokens and of the oversubscribed SOL,
+    // both rounded down. The last claim routes the rounding dust into the reserves.
+    pub fn claim_presale(&mut self, deposit: u64) -> Result<(u64, u64)> {
+        if matches!(self.status, PoolStatus::Presale | PoolStatus::Cancelled)
+            || self.presale.total_deposits == 0
+        {
+            return err!(CustomError::PresaleNotLaunched);
+        }
+
+        let total_deposits = self.presale.total_deposits;
+        let oversubscribed = total_deposits - self.presale.accepted_deposits;
+        let pro_rata = |amount: u64| -> Result<u64> {
+            let share = (deposit as u128)
+                .checked_mul(amount as u128)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?
+                / total_deposits as u128;
+            Ok(share as u64)
+        };
+        let tokens = pro_rata(self.presale.tokens_allocated)?;
+        let refund = pro_rata(oversubscribed)?;
+
+        self.presale.deposits_claimed = self
+            .presale
+            .deposits_claimed
+            .checked_add(deposit)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.presale.tokens_claimed += tokens;
+        self.presale.refunds_paid += refund;
+
+        if self.presale.deposits_claimed == total_deposits {
+            let token_dust = self.presale.tokens_allocated - self.presale.tokens_claimed;
+            let sol_dust = oversubscribed - self.presale.refunds_paid;
+            self.reserve_one = self
+                .reserve_one
+                .checked_add(token_dust)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+            self.reserve_two = self
+                .reserve_two
+                .checked_add(sol_dust)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+            msg!(
+                "Presale settled: {:?} tokens and {:?} lamports of dust to the pool",
+                token_dust,
+                sol_dust
+            );
+        }
+
+        Ok((tokens, refund))
+    }
+
+    // Books a supply minted straight into the pool. The matching shares are owned by
+    // no provider, so they stay locked and later deposits cannot claim the launch supply.
+    pub fn seed_token_supply(&mut self, amount: u64) -> Result<()> {
+        if amount == 0 || self.total_supply != 0 {
+            return err!(CustomError::FailedToAddLiquidity);
+        }
+
+        // same share scale as a first deposit, floored at one lamport for curves without virtual SOL
+        let sol_amount = cmp::max(self.virtual_sol_reserves, 1);
+        self.total_supply = integer_sqrt((amount as u128) * (sol_amount as u128));
+        self.reserve_one = amount;
+
+        Ok(())
+    }
+
+    // Either the global or the pool's pause stops the operation
+    pub fn ensure_not_paused(&self, config: &CurveConfiguration, withdrawal: bool) -> Result<()> {
+        config.pause.ensure_allows(withdrawal)?;
+        self.pause.ensure_allows(withdrawal)
+    }
+
+    // Puts a completed pool whose migration cannot go through back on its curve, so
+    // holders can sell and providers can withdraw. It completes again once the
+    // threshold is crossed.
+    pub fn reopen(&mut self) -> Result<()> {
+        if self.status != PoolStatus::Completed {
+            return err!(CustomError::PoolNotCompleted);
+        }
+
+        self.status = PoolStatus::Active;
+
+        Ok(())
+    }
+
+    pub fn ensure_active(&self) -> Result<()> {
+        if self.status != PoolStatus::Active {
+            return err!(CustomError::PoolNotActive);
+        }
+
+        Ok(())
+    }
+
+    // Caps a buy at the curve supply left before completion, charging only for what is bought
+    pub fn clamp_buy(
+        &self,
+        threshold: CompletionThreshold,
+        fee_bps: u16,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> Result<(u64, u64)> {
+        match threshold {
+            CompletionThreshold::Disabled => Ok((amount_in, amount_out)),
+            CompletionThreshold::TokensSold { amount } => {
+                let remaining = amount.saturating_sub(self.tokens_sold);
+                if amount_out <= remaining {
+                    return Ok((amount_in, amount_out));
+                }
+
+                let adjusted_amount =
+                    self.curve()
+                        .amount_in(&self.curve_state()?, remaining, TradeDirection::Buy)?;
+
+                Ok((
+                    calculate_amount_before_fee(adjusted_amount, fee_bps as u64)?,
+                    remaining,
+                ))
+            }
+            CompletionThreshold::SolRaised { lamports } => {
+                // the fee goes to the treasury, only the rest counts towards the threshold
+                let remaining = lamports.saturating_sub(self.reserve_two);
+                let adjusted_amount = amount_in
+                    .checked_sub(calculate_fee(amount_in, fee_bps as u64)?)
+                    .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+                if adjusted_amount <= remaining {
+                    return Ok((amount_in, amount_out));
+                }
+
+                let amount_in = calculate_amount_before_fee(remaining, fee_bps as u64)?;
+                let adjusted_amount = amount_in
+                    .checked_sub(calculate_fee(amount_in, fee_bps as u64)?)
+                    .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+                let amount_out = self.curve().amount_out(
+                    &self.curve_state()?,
+                    adjusted_amount,
+                    TradeDirection::Buy,
+                )?;
+
+                Ok((amount_in, amount_out))
+            }
+        }
+    }
+
+    // Quotes an opening buy of up to `lamports` the way a swap would, trimmed to the
+    // completion threshold and to the tokens the pool holds. Returns the lamports
+    // spent, fee included, and the tokens bought.
+    pub fn quote_opening_buy(
+        &self,
+        threshold: CompletionThreshold,
+        fee_bps: u16,
+        lamports: u64,
+    ) -> Result<(u64, u64)> {
+        if lamports == 0 {
+            return Ok((0, 0));
+        }
+
+        let fee = calculate_fee(lamports, fee_bps as u64)?;
+        let curve_state = self.curve_state()?;
+        let amount_out =
+            self.curve()
+                .amount_out(&curve_state, lamports - fee, TradeDirection::Buy)?;
+        let (amount_in, amount_out) = self.clamp_buy(threshold, fee_bps, lamports, amount_out)?;
+        if amount_out <= self.reserve_one {
+            return Ok((cmp::min(amount_in, lamports), amount_out));
+        }
+
+        // virtual reserves can quote more than the pool actually holds
+        let adjusted_amount =
+            self.curve()
+                .amount_in(&curve_state, self.reserve_one, TradeDirection::Buy)?;
+        let amount_in = calculate_amount_before_fee(adjusted_amount, fee_bps as u64)?;
+
+        Ok((cmp::min(amount_in, lamports), self.reserve_one))
+    }
+
+    // Flips the pool to Completed once its threshold is crossed
+    pub fn update_status(&mut self, threshold: CompletionThreshold) {
+        let completed = match threshold {
+            CompletionThreshold::Disabled => false,
+            CompletionThreshold::SolRaised { lamports } => self.reserve_two >= lamports,
+            CompletionThreshold::TokensSold { amount } => self.tokens_sold >= amount,
+        };
+
+        if completed {
+            msg!("Curve completed: {:?}", self.token_one);
+            self.status = PoolStatus::Completed;
+        }
+    }
+
+    pub fn curve(&self) -> Box<dyn Curve> {
+        let [first, second, third] = self.curve_params;
+
+        match self.curve_type {
+            CurveType::ConstantProduct => Box::new(ConstantProductCurve),
+            CurveType::Linear => Box::new(LinearCurve {
+                base_price: first,
+                slope: second,
+            }),
+            CurveType::Exponential => Box::new(ExponentialCurve {
+                base_price: first,
+                growth_rate: second,
+            }),
+            CurveType::Sigmoid => Box::new(SigmoidCurve::new(first, second, third)),
+        }
+    }
+
+    pub fn curve_state(&self) -> Result<CurveState> {
+        let (token_reserves, sol_reserves) = self.effective_reserves()?;
+
+        Ok(CurveState {
+            token_reserves,
+            sol_reserves,
+            tokens_sold: self.tokens_sold,
+            real_token_reserves: self.reserve_one,
+        })
+    }
+
+    // Reserves the curve prices against: (token, SOL), virtual + real
+    pub fn effective_reserves(&self) -> Result<(u64, u64)> {
+        let token_reserves = self
+            .virtual_token_reserves
+            .checked_add(self.reserve_one)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        let sol_reserves = self
+            .virtual_sol_reserves
+            .checked_add(self.reserve_two)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        Ok((token_reserves, sol_reserves))
     }
 }
 
@@ -95,6 +1412,7 @@ pub trait LiquidityPoolAccount<'info> {
     fn update_reserves(&mut self, reserve_one: u64, reserve_two: u64) -> Result<()>;
 
     // Allows adding liquidity by depositing an amount of two tokens and getting back pool shares
+    #[allow(clippy::too_many_arguments)]
     fn add_liquidity(
         &mut self,
         token_one_accounts: (
@@ -102,14 +1420,17 @@ pub trait LiquidityPoolAccount<'info> {
             &mut Account<'info, TokenAccount>,
             &mut Account<'info, TokenAccount>,
         ),
+        sol_vault: &AccountInfo<'info>,
         amount_one: u64,
         amount_two: u64,
         liquidity_provider_account: &mut Account<'info, LiquidityProvider>,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
     ) -> Result<()>;
 
     // Allows removing liquidity by burning pool shares and receiving back a proportionate amount of tokens
+    #[allow(clippy::too_many_arguments)]
     fn remove_liquidity(
         &mut self,
         token_one_accounts: (
@@ -117,12 +1438,15 @@ pub trait LiquidityPoolAccount<'info> {
             &mut Account<'info, TokenAccount>,
             &mut Account<'info, TokenAccount>,
         ),
+        sol_vault: &AccountInfo<'info>,
         shares: u64,
         liquidity_provider_account: &mut Account<'info, LiquidityProvider>,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
     ) -> Result<()>;
 
+    #[allow(clippy::too_many_arguments)]
     fn swap(
         &mut self,
         bonding_configuration_account: &Account<'info, CurveConfiguration>,
@@ -131,18 +1455,75 @@ pub trait LiquidityPoolAccount<'info> {
             &mut Account<'info, TokenAccount>,
             &mut Account<'info, TokenAccount>,
         ),
+        sol_vault: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
         // token_two_accounts: (
         //     &mut Account<'info, Mint>,
         //     &mut AccountInfo<'info>,
         //     &mut Signer<'info>,
         // ),
         amount: u64,
-        style: u64,
+        direction: TradeDirection,
+        min_amount_out: u64,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+
+    // Swaps for an exact output amount, charging at most max_amount_in
+    #[allow(clippy::too_many_arguments)]
+    fn swap_exact_out(
+        &mut self,
+        bonding_configuration_account: &Account<'info, CurveConfiguration>,
+        token_one_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        sol_vault: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        amount_out: u64,
+        direction: TradeDirection,
+        max_amount_in: u64,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
     ) -> Result<()>;
 
+    // Moves a quoted swap into the reserves and transfers both legs. The fee, in SOL,
+    // bypasses the reserves and goes to the treasury.
+    #[allow(clippy::too_many_arguments)]
+    fn settle_swap(
+        &mut self,
+        token_one_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        sol_vault: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        amount_in: u64,
+        fee: u64,
+        amount_out: u64,
+        direction: TradeDirection,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+
+    // Hands a completed curve's reserves to the migrating payer, SOL wrapped as WSOL
+    #[allow(clippy::too_many_arguments)]
+    fn release_for_migration(
+        &mut self,
+        target: MigrationTarget,
+        pool_token_account: &Account<'info, TokenAccount>,
+        payer_token_account: &Account<'info, TokenAccount>,
+        sol_vault: &AccountInfo<'info>,
+        payer_wsol_account: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<(u64, u64)>;
+
     fn transfer_token_from_pool(
         &self,
         from: &Account<'info, TokenAccount>,
@@ -168,6 +1549,7 @@ pub trait LiquidityPoolAccount<'info> {
         system_program: &Program<'info, System>,
     ) -> Result<()>;
 
+    // Pays out of the SOL vault, which always keeps its rent-exempt minimum
     fn transfer_sol_from_pool(
         &self,
         from: &AccountInfo<'info>,
@@ -228,20 +1610,29 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             &mut Account<'info, TokenAccount>,
             &mut Account<'info, TokenAccount>,
         ),
+        sol_vault: &AccountInfo<'info>,
         amount_one: u64,
         amount_two: u64,
         liquidity_provider_account: &mut Account<'info, LiquidityProvider>,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
     ) -> Result<()> {
-        let shares_to_allocate;
+        // a presale pool is seeded before it launches
+        if !matches!(
+            self.status,
+            PoolStatus::Active | PoolStatus::Presale | PoolStatus::Auction | PoolStatus::Batching
+        ) {
+            return err!(CustomError::PoolNotActive);
+        }
 
-        if self.total_supply == 0 {
-            let sqrt_shares = (convert_to_float(amount_one, token_one_accounts.0.decimals)
-                .mul(convert_to_float(amount_two, 9 as u8)))
-            .sqrt();
+        let shares_to_allocate = if self.total_supply == 0 {
+            // the virtual SOL reserve lets a curve launch with tokens only
+            let sol_amount = amount_two
+                .checked_add(self.virtual_sol_reserves)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
-            shares_to_allocate = sqrt_shares as u64;
+            integer_sqrt((amount_one as u128) * (sol_amount as u128))
         } else {
             let mul_value = amount_one
                 .checked_mul(self.total_supply)
@@ -250,17 +1641,22 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
                 .checked_div(self.reserve_one)
                 .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
-            let mul_value = amount_two
-                .checked_mul(self.total_supply)
-                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-            let shares_two = mul_value
-                .checked_div(self.reserve_two)
-                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-
-            shares_to_allocate = cmp::min(shares_one, shares_two);
-        }
-
-        if shares_to_allocate <= 0 {
+            // a pool launched without SOL is priced on its virtual reserve only
+            if self.reserve_two == 0 {
+                shares_one
+            } else {
+                let mul_value = amount_two
+                    .checked_mul(self.total_supply)
+                    .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+                let shares_two = mul_value
+                    .checked_div(self.reserve_two)
+                    .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+                cmp::min(shares_one, shares_two)
+            }
+        };
+
+        if shares_to_allocate == 0 {
             return err!(CustomError::FailedToAddLiquidity);
         }
 
@@ -285,6 +1681,8 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             token_program,
         )?;
 
+        self.transfer_sol_to_pool(authority, sol_vault, amount_two, system_program)?;
+
         Ok(())
     }
 
@@ -295,12 +1693,19 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             &mut Account<'info, TokenAccount>,
             &mut Account<'info, TokenAccount>,
         ),
+        sol_vault: &AccountInfo<'info>,
         shares: u64,
         liquidity_provider_account: &mut Account<'info, LiquidityProvider>,
-        _authority: &Signer<'info>,
+        authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
     ) -> Result<()> {
-        if shares <= 0 {
+        // a completed curve's reserves are held for migration, a cancelled launch unwinds
+        if !matches!(self.status, PoolStatus::Active | PoolStatus::Cancelled) {
+            return err!(CustomError::PoolNotActive);
+        }
+
+        if shares == 0 {
             return err!(CustomError::FailedToRemoveLiquidity);
         }
 
@@ -324,7 +1729,8 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             .checked_div(self.total_supply)
             .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
-        if amount_out_one <= 0 || amount_out_two <= 0 {
+        // one side may legitimately be empty on a curve launched without SOL
+        if amount_out_one == 0 && amount_out_two == 0 {
             return err!(CustomError::FailedToRemoveLiquidity);
         }
 
@@ -348,116 +1754,251 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             token_program,
         )?;
 
+        self.transfer_sol_from_pool(sol_vault, authority, amount_out_two, system_program)?;
+
         Ok(())
     }
 
     fn swap(
         &mut self,
-        _bonding_configuration_account: &Account<'info, CurveConfiguration>,
+        bonding_configuration_account: &Account<'info, CurveConfiguration>,
         token_one_accounts: (
             &mut Account<'info, Mint>,
             &mut Account<'info, TokenAccount>,
             &mut Account<'info, TokenAccount>,
         ),
+        sol_vault: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
         // token_two_accounts: (
         //     &mut Account<'info, Mint>,
         //     &mut AccountInfo<'info>,
         //     &mut Signer<'info>,
         // ),
         amount: u64,
-        style: u64,
+        direction: TradeDirection,
+        min_amount_out: u64,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
     ) -> Result<()> {
-        if amount <= 0 {
+        if amount == 0 {
             return err!(CustomError::InvalidAmount);
         }
+        self.ensure_active()?;
         msg!("Mint: {:?} ", token_one_accounts.0.key());
-        msg!("Swap: {:?} {:?} {:?}", authority.key(), style, amount);
+        msg!("Swap: {:?} {:?} {:?}", authority.key(), direction, amount);
+
+        // priced on the pool's curve, paid out of the real reserves. Fees are
+        // charged in SOL, on what a buy pays in and on what a sell pays out.
+        let threshold = bonding_configuration_account.completion_threshold;
+        let (amount_in, fee, amount_out) = if direction == TradeDirection::Buy {
+            let fee_bps = bonding_configuration_account.buy_fee_bps;
+            let adjusted_amount = amount
+                .checked_sub(calculate_fee(amount, fee_bps as u64)?)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+            let amount_out =
+                self.curve()
+                    .amount_out(&self.curve_state()?, adjusted_amount, direction)?;
+            let (amount_in, amount_out) = self.clamp_buy(threshold, fee_bps, amount, amount_out)?;
+
+            (
+                amount_in,
+                calculate_fee(amount_in, fee_bps as u64)?,
+                amount_out,
+            )
+        } else {
+            let fee_bps = bonding_configuration_account.sell_fee_bps;
+            let gross_amount_out =
+                self.curve()
+                    .amount_out(&self.curve_state()?, amount, direction)?;
+            let fee = calculate_fee(gross_amount_out, fee_bps as u64)?;
+            let amount_out = gross_amount_out
+                .checked_sub(fee)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+            (amount, fee, amount_out)
+        };
+
+        if amount_out < min_amount_out {
+            return err!(CustomError::SlippageExceeded);
+        }
+
+        self.settle_swap(
+            token_one_accounts,
+            sol_vault,
+            treasury,
+            amount_in,
+            fee,
+            amount_out,
+            direction,
+            authority,
+            token_program,
+            system_program,
+        )?;
+        self.update_status(threshold);
 
-        // xy = k => Constant product formula
-        // (x + dx)(y - dy) = k
-        // y - dy = k / (x + dx)
-        // y - dy = xy / (x + dx)
-        // dy = y - (xy / (x + dx))
-        // dy = yx + ydx - xy / (x + dx)
-        // formula => dy = ydx / (x + dx)
+        Ok(())
+    }
 
-        let adjusted_amount_in_float = convert_to_float(amount, token_one_accounts.0.decimals)
-            .div(100_f64)
-            .mul(100_f64.sub(_bonding_configuration_account.fees));
+    fn swap_exact_out(
+        &mut self,
+        bonding_configuration_account: &Account<'info, CurveConfiguration>,
+        token_one_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        sol_vault: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        amount_out: u64,
+        direction: TradeDirection,
+        max_amount_in: u64,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        if amount_out == 0 {
+            return err!(CustomError::InvalidAmount);
+        }
+        self.ensure_active()?;
+        msg!("Mint: {:?} ", token_one_accounts.0.key());
+        msg!(
+            "Swap exact out: {:?} {:?} {:?}",
+            authority.key(),
+            direction,
+            amount_out
+        );
+
+        // Inverse of the pool's curve, grossed up so that the fee charged on
+        // a buy's input leaves the curve's input, and the fee charged on a
+        // sell's output leaves the exact output
+
+        let (fee_bps, adjusted_amount_out) = if direction == TradeDirection::Sell {
+            let fee_bps = bonding_configuration_account.sell_fee_bps;
+            (
+                fee_bps,
+                calculate_amount_before_fee(amount_out, fee_bps as u64)?,
+            )
+        } else {
+            (bonding_configuration_account.buy_fee_bps, amount_out)
+        };
 
         let adjusted_amount =
-            convert_from_float(adjusted_amount_in_float, token_one_accounts.0.decimals);
+            self.curve()
+                .amount_in(&self.curve_state()?, adjusted_amount_out, direction)?;
+        let (amount_in, fee) = if direction == TradeDirection::Sell {
+            (adjusted_amount, adjusted_amount_out - amount_out)
+        } else {
+            let amount_in = calculate_amount_before_fee(adjusted_amount, fee_bps as u64)?;
+            (amount_in, calculate_fee(amount_in, fee_bps as u64)?)
+        };
 
-        if style == 1 {
-            let denominator_sum = self
-                .reserve_one
-                .checked_add(adjusted_amount)
-                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        if amount_in == 0 {
+            return err!(CustomError::InvalidAmount);
+        }
 
-            let div_amt = convert_to_float(denominator_sum, token_one_accounts.0.decimals).div(
-                convert_to_float(adjusted_amount, token_one_accounts.0.decimals),
-            );
+        // an exact output is never trimmed to what is left before completion
+        let threshold = bonding_configuration_account.completion_threshold;
+        if direction == TradeDirection::Buy {
+            let (_, available) = self.clamp_buy(threshold, fee_bps, amount_in, amount_out)?;
+            if available < amount_out || amount_out > self.reserve_one {
+                return err!(CustomError::ExactOutputUnavailable);
+            }
+        }
+
+        if amount_in > max_amount_in {
+            return err!(CustomError::ExcessiveInputAmount);
+        }
 
-            let amount_out_in_float = convert_to_float(self.reserve_two, 9 as u8).div(div_amt);
+        self.settle_swap(
+            token_one_accounts,
+            sol_vault,
+            treasury,
+            amount_in,
+            fee,
+            amount_out,
+            direction,
+            authority,
+            token_program,
+            system_program,
+        )?;
+        self.update_status(threshold);
 
-            let amount_out = convert_from_float(amount_out_in_float, 9 as u8);
+        Ok(())
+    }
 
+    fn settle_swap(
+        &mut self,
+        token_one_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        sol_vault: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        amount_in: u64,
+        fee: u64,
+        amount_out: u64,
+        direction: TradeDirection,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        if direction == TradeDirection::Sell {
             let new_reserves_one = self
                 .reserve_one
-                .checked_add(amount)
+                .checked_add(amount_in)
                 .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
+            // the virtual reserve can quote more SOL than the pool really holds
             let new_reserves_two = self
                 .reserve_two
                 .checked_sub(amount_out)
-                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+                .and_then(|reserve| reserve.checked_sub(fee))
+                .ok_or(CustomError::InsufficientFunds)?;
 
             self.update_reserves(new_reserves_one, new_reserves_two)?;
+            // tokens sold into a constant product pool may have come from its LPs
+            self.tokens_sold = self.tokens_sold.saturating_sub(amount_in);
             msg! {"Reserves: {:?} {:?}", new_reserves_one, new_reserves_two}
             self.transfer_token_to_pool(
                 token_one_accounts.2,
                 token_one_accounts.1,
-                amount,
+                amount_in,
                 authority,
                 token_program,
             )?;
 
             self.transfer_sol_from_pool(
                 // token_two_accounts.1,
-                &self.to_account_info(),
+                sol_vault,
                 // token_two_accounts.2,
                 authority,
                 amount_out,
                 system_program,
             )?;
+            if fee > 0 {
+                self.transfer_sol_from_pool(sol_vault, treasury, fee, system_program)?;
+            }
         } else {
-            let denominator_sum = self
-                .reserve_two
-                .checked_add(adjusted_amount)
-                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-
-            let div_amt = convert_to_float(denominator_sum, token_one_accounts.0.decimals).div(
-                convert_to_float(adjusted_amount, token_one_accounts.0.decimals),
-            );
-
-            let amount_out_in_float = convert_to_float(self.reserve_one, 9 as u8).div(div_amt);
-
-            let amount_out = convert_from_float(amount_out_in_float, 9 as u8);
-
             let new_reserves_one = self
                 .reserve_one
                 .checked_sub(amount_out)
-                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+                .ok_or(CustomError::InsufficientFunds)?;
 
+            let adjusted_amount = amount_in
+                .checked_sub(fee)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
             let new_reserves_two = self
                 .reserve_two
-                .checked_add(amount)
+                .checked_add(adjusted_amount)
                 .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
             self.update_reserves(new_reserves_one, new_reserves_two)?;
+            self.tokens_sold = self
+                .tokens_sold
+                .checked_add(amount_out)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
             msg! {"Reserves: {:?} {:?}", new_reserves_one, new_reserves_two}
             self.transfer_token_from_pool(
@@ -471,14 +2012,66 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
                 // token_two_accounts.2,
                 authority,
                 // token_two_accounts.1,
-                &self.to_account_info(),
-                amount,
+                sol_vault,
+                adjusted_amount,
                 system_program,
             )?;
+            if fee > 0 {
+                self.transfer_sol_to_pool(authority, treasury, fee, system_program)?;
+            }
         }
         Ok(())
     }
 
+    fn release_for_migration(
+        &mut self,
+        target: MigrationTarget,
+        pool_token_account: &Account<'info, TokenAccount>,
+        payer_token_account: &Account<'info, TokenAccount>,
+        sol_vault: &AccountInfo<'info>,
+        payer_wsol_account: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<(u64, u64)> {
+        if self.status != PoolStatus::Completed {
+            return err!(CustomError::PoolNotCompleted);
+        }
+
+        if self.migration_target != target {
+            return err!(CustomError::InvalidMigrationTarget);
+        }
+
+        let token_amount = self.reserve_one;
+        let sol_amount = self.reserve_two;
+        if token_amount == 0 || sol_amount == 0 {
+            return err!(CustomError::InvalidAmount);
+        }
+
+        self.transfer_token_from_pool(
+            pool_token_account,
+            payer_token_account,
+            token_amount,
+            token_program,
+        )?;
+        self.transfer_sol_from_pool(
+            sol_vault,
+            &payer_wsol_account.to_account_info(),
+            sol_amount,
+            system_program,
+        )?;
+        token::sync_native(CpiContext::new(
+            token_program.to_account_info(),
+            token::SyncNative {
+                account: payer_wsol_account.to_account_info(),
+            },
+        ))?;
+
+        self.update_reserves(0, 0)?;
+        self.status = PoolStatus::Migrated;
+
+        Ok((token_amount, sol_amount))
+    }
+
     fn transfer_token_from_pool(
         &self,
         from: &Account<'info, TokenAccount>,
@@ -563,17 +2156,27 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         amount: u64,
         system_program: &Program<'info, System>,
     ) -> Result<()> {
+        let remaining = from
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(CustomError::InsufficientFunds)?;
+
+        if remaining < sol_vault_minimum_balance()? {
+            return err!(CustomError::VaultBelowRentExemption);
+        }
+
+        let pool_key = self.key();
         system_program::transfer(
             CpiContext::new_with_signer(
                 system_program.to_account_info(),
                 system_program::Transfer {
-                    from: from.to_account_info().clone(),
+                    from: from.clone(),
                     to: to.clone(),
                 },
                 &[&[
-                    LiquidityPool::POOL_SEED_PREFIX.as_bytes(),
-                    self.token_one.key().as_ref(),
-                    &[self.bump],
+                    LiquidityPool::SOL_VAULT_SEED_PREFIX.as_bytes(),
+                    pool_key.as_ref(),
+                    &[self.sol_vault_bump],
                 ]],
             ),
             amount,
@@ -629,3 +2232,231 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_account(fees: f64) -> Vec<u8> {
+        let mut data = CurveConfiguration::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&fees.to_le_bytes());
+        data.extend_from_slice(&[0u8; 32]);
+        data
+    }
+
+    #[test]
+    fn legacy_fee_bps_rounds_to_the_nearest() {
+        assert_eq!(CurveConfiguration::legacy_fee_bps(0.0).unwrap(), 0);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(1.0).unwrap(), 100);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(0.25).unwrap(), 25);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(0.014).unwrap(), 1);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(0.016).unwrap(), 2);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(0.004).unwrap(), 0);
+    }
+
+    #[test]
+    fn legacy_fee_bps_caps_at_the_maximum() {
+        assert_eq!(CurveConfiguration::legacy_fee_bps(10.0).unwrap(), 1_000);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(10.01).unwrap(), 1_000);
+        assert_eq!(CurveConfiguration::legacy_fee_bps(100.0).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn legacy_fee_bps_rejects_out_of_range_fees() {
+        assert!(CurveConfiguration::legacy_fee_bps(-0.01).is_err());
+        assert!(CurveConfiguration::legacy_fee_bps(100.01).is_err());
+        assert!(CurveConfiguration::legacy_fee_bps(f64::NAN).is_err());
+        assert!(CurveConfiguration::legacy_fee_bps(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn migrates_a_legacy_account() {
+        let admin = Pubkey::new_unique();
+        let migrated = CurveConfiguration::from_legacy(&legacy_account(1.5), admin, 3_600).unwrap();
+
+        // written back the way migrate_config does after the realloc
+        let mut data = vec![0u8; CurveConfiguration::ACCOUNT_SIZE];
+        migrated.try_serialize(&mut &mut data[..]).unwrap();
+        let config = CurveConfiguration::try_deserialize(&mut &data[..]).unwrap();
+
+        assert_eq!(config.buy_fee_bps, 150);
+        assert_eq!(config.sell_fee_bps, 150);
+        assert_eq!(config.initial_virtual_sol_reserves, 0);
+        assert_eq!(config.initial_virtual_token_reserves, 0);
+        assert_eq!(config.completion_threshold, CompletionThreshold::Disabled);
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.treasury, admin);
+        assert_eq!(config.pending_admin, None);
+        assert_eq!(config.pause, PauseState::Unpaused);
+        assert!(config.multisig_signers.is_empty());
+        assert_eq!(config.multisig_threshold, 0);
+        assert_eq!(config.proposal_count, 0);
+        assert_eq!(config.min_update_delay, 3_600);
+        assert_eq!(config.raydium_amm_config, Pubkey::default());
+    }
+
+    #[test]
+    fn migrated_configuration_enforces_its_update_delay() {
+        let admin = Pubkey::new_unique();
+        let config = CurveConfiguration::from_legacy(&legacy_account(1.0), admin, 3_600).unwrap();
+        let update = ConfigUpdate {
+            buy_fee_bps: 200,
+            sell_fee_bps: 200,
+            initial_virtual_sol_reserves: 0,
+            initial_virtual_token_reserves: 0,
+            completion_threshold: CompletionThreshold::Disabled,
+            treasury: admin,
+            raydium_amm_config: Pubkey::default(),
+        };
+
+        let pending = PendingUpdate::new(update, &config, admin, 1_000).unwrap();
+        assert_eq!(pending.effective_at, 4_600);
+        assert!(pending.ensure_effective(4_599).is_err());
+        pending.ensure_effective(4_600).unwrap();
+
+        assert!(CurveConfiguration::validate_update_delay(-1).is_err());
+    }
+
+    #[test]
+    fn rejects_accounts_without_the_legacy_layout() {
+        let admin = Pubkey::new_unique();
+
+        let mut data = legacy_account(1.0);
+        data.push(0);
+        assert!(CurveConfiguration::from_legacy(&data, admin, 0).is_err());
+
+        let mut data = legacy_account(1.0);
+        data[0] ^= 1;
+        assert!(CurveConfiguration::from_legacy(&data, admin, 0).is_err());
+
+        assert!(CurveConfiguration::from_legacy(&legacy_account(101.0), admin, 0).is_err());
+    }
+
+    fn pool(curve_type: CurveType, curve_params: [u64; 3]) -> LiquidityPool {
+        let config = CurveConfiguration::new(
+            100,
+            100,
+            0,
+            0,
+            CompletionThreshold::Disabled,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+        );
+
+        LiquidityPool::new(
+            Pubkey::new_unique(),
+            255,
+            255,
+            &config,
+            curve_type,
+            curve_params,
+            MigrationTarget::Raydium,
+        )
+    }
+
+    fn auction_pool(curve_type: CurveType, curve_params: [u64; 3]) -> LiquidityPool {
+        let mut pool = pool(curve_type, curve_params);
+        pool.reserve_one = 1_000_000_000_000_000;
+        pool.status = PoolStatus::Auction;
+        pool.auction = DutchAuction {
+            start_slot: 0,
+            end_slot: 10,
+            start_price: 2_000_000,
+            floor_price: 1_000_000,
+            tranche: 1_000_000_000_000,
+            ..DutchAuction::default()
+        };
+        pool
+    }
+
+    #[test]
+    fn auction_buyers_sell_back_within_the_sol_they_paid() {
+        let mut pool = auction_pool(CurveType::Linear, [30_000, 1_000, 0]);
+
+        let (tokens, cost) = pool
+            .record_auction_buy(CompletionThreshold::Disabled, 500_000_000_000, 10)
+            .unwrap();
+        assert_eq!(pool.tokens_sold, tokens);
+        assert_eq!(pool.reserve_two, cost);
+
+        pool.end_auction(CompletionThreshold::Disabled, 11).unwrap();
+        let out = pool
+            .curve()
+            .amount_out(&pool.curve_state().unwrap(), tokens, TradeDirection::Sell)
+            .unwrap();
+        assert!(out > 0);
+        assert!(out <= pool.reserve_two);
+    }
+
+    #[test]
+    fn auction_tokens_count_toward_the_tokens_sold_threshold() {
+        let mut pool = auction_pool(CurveType::Linear, [30_000, 1_000, 0]);
+        let threshold = CompletionThreshold::TokensSold {
+            amount: 1_000_000_000_000,
+        };
+
+        pool.record_auction_buy(threshold, 1_000_000_000_000, 10)
+            .unwrap();
+
+        assert_eq!(pool.status, PoolStatus::Completed);
+    }
+
+    #[test]
+    fn presale_fees_stay_out_of_the_reserves() {
+        let mut pool = pool(CurveType::Linear, [30_000, 1_000, 0]);
+        pool.reserve_one = 1_000_000_000_000_000;
+        pool.status = PoolStatus::Presale;
+        pool.presale = Presale {
+            end_slot: 10,
+            soft_cap: 1_000_000_000,
+            hard_cap: 2_000_000_000,
+            total_deposits: 3_000_000_000,
+            ..Presale::default()
+        };
+
+        let fee = pool
+            .launch_presale(CompletionThreshold::Disabled, 100, 11)
+            .unwrap();
+
+        assert_eq!(fee, 20_000_000);
+        assert_eq!(pool.presale.accepted_deposits, 2_000_000_000);
+        assert_eq!(pool.reserve_two, 2_000_000_000 - fee);
+    }
+
+    #[test]
+    fn buy_fees_do_not_count_toward_the_sol_raised_threshold() {
+        let mut pool = pool(CurveType::Linear, [30_000, 1_000, 0]);
+        pool.reserve_one = 1_000_000_000_000_000;
+        let threshold = CompletionThreshold::SolRaised {
+            lamports: 1_000_000_000,
+        };
+
+        let (amount_in, _) = pool
+            .quote_opening_buy(threshold, 100, 5_000_000_000)
+            .unwrap();
+        let fee = calculate_fee(amount_in, 100).unwrap();
+        assert_eq!(
+            amount_in,
+            calculate_amount_before_fee(1_000_000_000, 100).unwrap()
+        );
+
+        pool.reserve_two = amount_in - fee;
+        pool.update_status(threshold);
+        assert_eq!(pool.status, PoolStatus::Completed);
+    }
+
+    #[test]
+    fn only_a_completed_pool_reopens() {
+        let mut pool = pool(CurveType::ConstantProduct, [0; 3]);
+
+        assert!(pool.reopen().is_err());
+
+        pool.status = PoolStatus::Completed;
+        pool.reopen().unwrap();
+        assert_eq!(pool.status, PoolStatus::Active);
+
+        pool.status = PoolStatus::Migrated;
+        assert!(pool.reopen().is_err());
+    }
+}
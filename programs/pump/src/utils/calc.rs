@@ -1,9 +1,195 @@
-use std::ops::{Div, Mul};
+use crate::errors::CustomError;
+use anchor_lang::prelude::*;
 
-pub fn convert_to_float(value: u64, decimals: u8) -> f64 {
-    (value as f64).div(f64::powf(10.0, decimals as f64))
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
+
+// All curve math runs on u128 intermediates over raw token units (no decimals scaling),
+// and every division rounds in the pool's favor so off-chain quotes match on-chain results.
+
+// Exact input on a constant product curve, rounded down
+// (x + dx)(y - dy) = xy => dy = y * dx / (x + dx)
+pub fn calculate_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    let numerator = (reserve_out as u128)
+        .checked_mul(amount_in as u128)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    let denominator = (reserve_in as u128)
+        .checked_add(amount_in as u128)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    let amount_out = numerator
+        .checked_div(denominator)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    to_u64(amount_out)
+}
+
+// Input required for an exact output on a constant product curve, rounded up
+// (x + dx)(y - dy) = xy => dx = x * dy / (y - dy)
+pub fn calculate_amount_in(amount_out: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    if amount_out >= reserve_out {
+        return err!(CustomError::InsufficientFunds);
+    }
+
+    let numerator = (reserve_in as u128)
+        .checked_mul(amount_out as u128)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    let denominator = (reserve_out - amount_out) as u128;
+
+    to_u64(numerator.div_ceil(denominator))
 }
 
-pub fn convert_from_float(value: f64, decimals: u8) -> u64 {
-    value.mul(f64::powf(10.0, decimals as f64)) as u64
+// Fee charged on an amount, rounded up
+pub fn calculate_fee(amount: u64, fee_bps: u64) -> Result<u64> {
+    let fee = mul_div_ceil(amount, fee_bps, BASIS_POINTS_DIVISOR)?;
+
+    Ok(fee)
+}
+
+// Gross amount whose fee leaves at least `amount` once deducted, rounded up
+pub fn calculate_amount_before_fee(amount: u64, fee_bps: u64) -> Result<u64> {
+    let denominator = BASIS_POINTS_DIVISOR
+        .checked_sub(fee_bps)
+        .ok_or(CustomError::InvalidFee)?;
+
+    mul_div_ceil(amount, BASIS_POINTS_DIVISOR, denominator)
+}
+
+// a * b / c, rounded up
+pub fn mul_div_ceil(a: u64, b: u64, c: u64) -> Result<u64> {
+    if c == 0 {
+        return err!(CustomError::OverflowOrUnderflowOccurred);
+    }
+
+    let value = (a as u128)
+        .checked_mul(b as u128)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?
+        .div_ceil(c as u128);
+
+    to_u64(value)
+}
+
+// Integer square root, rounded down
+pub fn integer_sqrt(value: u128) -> u64 {
+    if value < 2 {
+        return value as u64;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x as u64
+}
+
+pub fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| error!(CustomError::OverflowOrUnderflowOccurred))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_out_rounds_down() {
+        // 1000 * 10 / (1000 + 10) = 9.9
+        assert_eq!(calculate_amount_out(10, 1_000, 1_000).unwrap(), 9);
+        assert_eq!(calculate_amount_out(0, 1_000, 1_000).unwrap(), 0);
+        // the output never reaches the reserve, however large the input
+        assert_eq!(
+            calculate_amount_out(u64::MAX, 1, u64::MAX).unwrap(),
+            u64::MAX - 1
+        );
+    }
+
+    #[test]
+    fn amount_out_fails_on_empty_reserves() {
+        assert!(calculate_amount_out(0, 0, 1_000).is_err());
+    }
+
+    #[test]
+    fn amount_in_rounds_up() {
+        // 1000 * 9 / (1000 - 9) = 9.08
+        assert_eq!(calculate_amount_in(9, 1_000, 1_000).unwrap(), 10);
+        // exact division is not rounded
+        assert_eq!(calculate_amount_in(500, 1_000, 1_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn amount_in_round_trips_with_amount_out() {
+        for amount_out in [1, 7, 999, 123_456] {
+            let amount_in = calculate_amount_in(amount_out, 3_000_000, 5_000_000).unwrap();
+
+            assert!(calculate_amount_out(amount_in, 3_000_000, 5_000_000).unwrap() >= amount_out);
+            assert!(
+                calculate_amount_out(amount_in - 1, 3_000_000, 5_000_000).unwrap() < amount_out
+            );
+        }
+    }
+
+    #[test]
+    fn amount_in_fails_past_the_reserve() {
+        assert!(calculate_amount_in(1_000, 1_000, 1_000).is_err());
+        assert!(calculate_amount_in(1_001, 1_000, 1_000).is_err());
+        // the largest output needs more input than fits in a u64
+        assert!(calculate_amount_in(u64::MAX - 1, u64::MAX, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn fee_rounds_up() {
+        assert_eq!(calculate_fee(10_000, 100).unwrap(), 100);
+        assert_eq!(calculate_fee(1, 1).unwrap(), 1);
+        assert_eq!(calculate_fee(10_001, 100).unwrap(), 101);
+        assert_eq!(calculate_fee(0, 100).unwrap(), 0);
+        assert_eq!(calculate_fee(12_345, 0).unwrap(), 0);
+        assert_eq!(
+            calculate_fee(u64::MAX, BASIS_POINTS_DIVISOR).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn amount_before_fee_covers_its_own_fee() {
+        for amount in [0, 1, 99, 10_000, 1_000_000_007] {
+            for fee_bps in [0, 1, 100, 1_000, 9_999] {
+                let gross = calculate_amount_before_fee(amount, fee_bps).unwrap();
+
+                assert!(gross - calculate_fee(gross, fee_bps).unwrap() >= amount);
+            }
+        }
+    }
+
+    #[test]
+    fn amount_before_fee_fails_at_the_limits() {
+        assert!(calculate_amount_before_fee(1, BASIS_POINTS_DIVISOR).is_err());
+        assert!(calculate_amount_before_fee(1, BASIS_POINTS_DIVISOR + 1).is_err());
+        assert!(calculate_amount_before_fee(u64::MAX, 1).is_err());
+        assert_eq!(calculate_amount_before_fee(u64::MAX, 0).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn integer_sqrt_rounds_down() {
+        assert_eq!(integer_sqrt(0), 0);
+        assert_eq!(integer_sqrt(1), 1);
+        assert_eq!(integer_sqrt(2), 1);
+        assert_eq!(integer_sqrt(3), 1);
+        assert_eq!(integer_sqrt(4), 2);
+        assert_eq!(integer_sqrt(99), 9);
+        assert_eq!(integer_sqrt(100), 10);
+        assert_eq!(
+            integer_sqrt(1_000_000_000_000_000 * 30_000_000_000),
+            5_477_225_575_051
+        );
+    }
+
+    #[test]
+    fn integer_sqrt_handles_the_largest_inputs() {
+        let max = u64::MAX as u128;
+
+        assert_eq!(integer_sqrt(max * max), u64::MAX);
+        assert_eq!(integer_sqrt(max * max - 1), u64::MAX - 1);
+        assert_eq!(integer_sqrt(u128::MAX), u64::MAX);
+    }
 }
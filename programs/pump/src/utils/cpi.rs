@@ -0,0 +1,95 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke,
+    },
+    system_program,
+};
+use anchor_spl::token::{self, Burn, Token, TokenAccount};
+
+// Calls a program we don't link against. Accounts are (account, writable) pairs in the
+// callee's order, and signers of this transaction stay signers in the call.
+pub fn invoke_external<'info>(
+    program: &AccountInfo<'info>,
+    data: Vec<u8>,
+    accounts: &[(AccountInfo<'info>, bool)],
+) -> Result<()> {
+    let metas = accounts
+        .iter()
+        .map(|(account, writable)| {
+            if *writable {
+                AccountMeta::new(account.key(), account.is_signer)
+            } else {
+                AccountMeta::new_readonly(account.key(), account.is_signer)
+            }
+        })
+        .collect();
+
+    let mut account_infos: Vec<AccountInfo<'info>> = accounts
+        .iter()
+        .map(|(account, _)| account.clone())
+        .collect();
+    account_infos.push(program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: program.key(),
+            accounts: metas,
+            data,
+        },
+        &account_infos,
+    )?;
+
+    Ok(())
+}
+
+// Burns every LP token a migration handed to the creator, so the liquidity stays in the
+// AMM pool for good. The LP account is created during the CPI, hence the raw account.
+pub fn burn_lp_tokens<'info>(
+    token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let amount = {
+        let data = token_account.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut &data[..])?.amount
+    };
+
+    token::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.clone(),
+                from: token_account.clone(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+// Lamports a pool's SOL vault never goes below. It only ever holds lamports, so
+// the minimum is that of an account without data.
+pub fn sol_vault_minimum_balance() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+// Funding a new SOL vault with its rent-exempt minimum creates it
+pub fn fund_sol_vault<'info>(
+    payer: &Signer<'info>,
+    sol_vault: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: payer.to_account_info(),
+                to: sol_vault.clone(),
+            },
+        ),
+        sol_vault_minimum_balance()?,
+    )
+}
@@ -0,0 +1,541 @@
+use crate::errors::CustomError;
+use crate::state::TradeDirection;
+use crate::utils::calc::{calculate_amount_in, calculate_amount_out, to_u64};
+use anchor_lang::prelude::*;
+
+// Supply-based prices are quoted in lamports per PRICE_SCALE raw token units
+pub const PRICE_SCALE: u128 = 1_000_000_000;
+
+// Fixed-point one used for exponents and logarithms
+pub const FIXED_ONE: u128 = 1_000_000_000_000;
+
+// ln(2) in FIXED_ONE units
+const LN_2: u128 = 693_147_180_560;
+
+// Series terms summed for e^r with r < ln 2, the last ones already round to zero
+const MAX_EXP_TERMS: u128 = 24;
+
+// Series terms summed for ln(1 + t), z <= 1/3 so z^(2k+1) rounds to zero by k = 14
+const MAX_LN_TERMS: usize = 16;
+
+// Compute cost of the supply curves. Quotes search the cost function by bisection, one
+// step per bit of the amount, so at most 64 steps. A buy evaluates the cost once per
+// step and a sell twice. Each evaluation sums one e^x of at most MAX_EXP_TERMS terms,
+// plus one ln(1 + t) of at most MAX_LN_TERMS terms on the sigmoid, every term one
+// u128 multiplication and division or two. That is more than the default compute unit
+// limit covers, so trades on these curves raise it, see the exponential and sigmoid
+// tests.
+
+// Pool state a curve prices against
+pub struct CurveState {
+    pub token_reserves: u64,      // Virtual + real tokens in the pool
+    pub sol_reserves: u64,        // Virtual + real SOL in the pool
+    pub tokens_sold: u64,         // Position along a supply-based curve
+    pub real_token_reserves: u64, // Tokens the pool actually holds
+}
+
+pub trait Curve {
+    // Output for an exact input, rounded in the pool's favor
+    fn amount_out(
+        &self,
+        state: &CurveState,
+        amount_in: u64,
+        direction: TradeDirection,
+    ) -> Result<u64>;
+
+    // Input required for an exact output, rounded in the pool's favor
+    fn amount_in(
+        &self,
+        state: &CurveState,
+        amount_out: u64,
+        direction: TradeDirection,
+    ) -> Result<u64>;
+}
+
+pub struct ConstantProductCurve;
+
+impl Curve for ConstantProductCurve {
+    fn amount_out(
+        &self,
+        state: &CurveState,
+        amount_in: u64,
+        direction: TradeDirection,
+    ) -> Result<u64> {
+        match direction {
+            TradeDirection::Sell => {
+                calculate_amount_out(amount_in, state.token_reserves, state.sol_reserves)
+            }
+            TradeDirection::Buy => {
+                calculate_amount_out(amount_in, state.sol_reserves, state.token_reserves)
+            }
+        }
+    }
+
+    fn amount_in(
+        &self,
+        state: &CurveState,
+        amount_out: u64,
+        direction: TradeDirection,
+    ) -> Result<u64> {
+        match direction {
+            TradeDirection::Sell => {
+                calculate_amount_in(amount_out, state.token_reserves, state.sol_reserves)
+            }
+            TradeDirection::Buy => {
+                calculate_amount_in(amount_out, state.sol_reserves, state.token_reserves)
+            }
+        }
+    }
+}
+
+// A curve defined by the total SOL it takes to sell `supply` tokens from zero.
+// The integer cost function is the curve itself, so buying and selling the same
+// tokens always moves the same lamports and rounding can never drain the pool.
+// Returns None once the cost no longer fits.
+pub trait SupplyCurve {
+    fn cost(&self, supply: u64) -> Option<u128>;
+
+    // SOL moved between two points on the curve
+    fn cost_between(&self, from: u64, to: u64) -> Result<u64> {
+        let cost_to = self
+            .cost(to)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        let cost_from = self
+            .cost(from)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+        to_u64(
+            cost_to
+                .checked_sub(cost_from)
+                .ok_or(CustomError::OverflowOrUnderflowOccurred)?,
+        )
+    }
+
+    // Largest token amount whose buy costs at most `budget`
+    fn max_buy(&self, tokens_sold: u64, max_tokens: u64, budget: u64) -> u64 {
+        let start = self.cost(tokens_sold);
+        let fits = |amount: u64| {
+            tokens_sold
+                .checked_add(amount)
+                .and_then(|to| self.cost(to)?.checked_sub(start?))
+                .is_some_and(|cost| cost <= budget as u128)
+        };
+
+        let (mut low, mut high) = (0_u64, max_tokens);
+        while low < high {
+            let mid = high - (high - low) / 2;
+            if fits(mid) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        low
+    }
+
+    // Smallest token amount whose sale returns at least `target`
+    fn min_sell(&self, tokens_sold: u64, target: u64) -> Result<u64> {
+        if self.cost_between(0, tokens_sold)? < target {
+            return err!(CustomError::InsufficientFunds);
+        }
+
+        let (mut low, mut high) = (0_u64, tokens_sold);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.cost_between(tokens_sold - mid, tokens_sold)? >= target {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        Ok(low)
+    }
+}
+
+impl<T: SupplyCurve> Curve for T {
+    fn amount_out(
+        &self,
+        state: &CurveState,
+        amount_in: u64,
+        direction: TradeDirection,
+    ) -> Result<u64> {
+        match direction {
+            TradeDirection::Buy => {
+                // virtual tokens only shape the constant product price
+                Ok(self.max_buy(state.tokens_sold, state.real_token_reserves, amount_in))
+            }
+            TradeDirection::Sell => {
+                // only tokens the curve has sold can be sold back into it
+                let from = state
+                    .tokens_sold
+                    .checked_sub(amount_in)
+                    .ok_or(CustomError::InsufficientFunds)?;
+
+                self.cost_between(from, state.tokens_sold)
+            }
+        }
+    }
+
+    fn amount_in(
+        &self,
+        state: &CurveState,
+        amount_out: u64,
+        direction: TradeDirection,
+    ) -> Result<u64> {
+        match direction {
+            TradeDirection::Buy => {
+                let to = state
+                    .tokens_sold
+                    .checked_add(amount_out)
+                    .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+                self.cost_between(state.tokens_sold, to)
+            }
+            TradeDirection::Sell => self.min_sell(state.tokens_sold, amount_out),
+        }
+    }
+}
+
+// price = base_price + slope * supply / PRICE_SCALE
+pub struct LinearCurve {
+    pub base_price: u64,
+    pub slope: u64,
+}
+
+impl SupplyCurve for LinearCurve {
+    // (base_price * s + slope * s^2 / (2 * PRICE_SCALE)) / PRICE_SCALE
+    fn cost(&self, supply: u64) -> Option<u128> {
+        let supply = supply as u128;
+        let base = (self.base_price as u128).checked_mul(supply)?;
+        let slope = (self.slope as u128)
+            .checked_mul(supply)?
+            .checked_div(PRICE_SCALE)?
+            .checked_mul(supply)?
+            .checked_div(2)?;
+
+        base.checked_add(slope)?.checked_div(PRICE_SCALE)
+    }
+}
+
+// price = base_price * e^(growth_rate * supply / PRICE_SCALE / FIXED_ONE)
+pub struct ExponentialCurve {
+    pub base_price: u64,
+    pub growth_rate: u64, // Exponent increase, in FIXED_ONE units, per PRICE_SCALE tokens
+}
+
+impl SupplyCurve for ExponentialCurve {
+    // base_price * (e^x - 1) / growth_rate, with x in FIXED_ONE units
+    fn cost(&self, supply: u64) -> Option<u128> {
+        let exponent = (supply as u128)
+            .checked_mul(self.growth_rate as u128)?
+            .checked_div(PRICE_SCALE)?;
+        let growth = exp_fixed(exponent)?.checked_sub(FIXED_ONE)?;
+
+        (self.base_price as u128)
+            .checked_mul(growth)?
+            .checked_div(self.growth_rate as u128)
+    }
+}
+
+// price = max_price / (1 + e^(-growth_rate * (supply - midpoint) / PRICE_SCALE / FIXED_ONE))
+pub struct SigmoidCurve {
+    max_price: u64,
+    growth_rate: u64,        // Steepness, in FIXED_ONE units per PRICE_SCALE tokens
+    midpoint: u64,           // Supply at which the price reaches half of max_price
+    base_area: Option<u128>, // softplus(x_0), worked out once instead of on every cost
+}
+
+impl SigmoidCurve {
+    pub fn new(max_price: u64, growth_rate: u64, midpoint: u64) -> Self {
+        let mut curve = Self {
+            max_price,
+            growth_rate,
+            midpoint,
+            base_area: None,
+        };
+        curve.base_area = curve.exponent(0).and_then(softplus_fixed);
+        curve
+    }
+
+    fn exponent(&self, supply: u64) -> Option<i128> {
+        (supply as i128 - self.midpoint as i128)
+            .checked_mul(self.growth_rate as i128)?
+            .checked_div(PRICE_SCALE as i128)
+    }
+}
+
+impl SupplyCurve for SigmoidCurve {
+    // max_price * (softplus(x_s) - softplus(x_0)) / growth_rate
+    fn cost(&self, supply: u64) -> Option<u128> {
+        let area = softplus_fixed(self.exponent(supply)?)?.checked_sub(self.base_area?)?;
+
+        (self.max_price as u128)
+            .checked_mul(area)?
+            .checked_div(self.growth_rate as u128)
+    }
+}
+
+// e^x for x in FIXED_ONE units, rounded down
+pub fn exp_fixed(x: u128) -> Option<u128> {
+    // e^x = 2^n * e^r with r in [0, ln 2)
+    let n = x / LN_2;
+    let r = x % LN_2;
+    if n >= 127 {
+        return None;
+    }
+
+    let mut term = FIXED_ONE;
+    let mut sum = FIXED_ONE;
+    for i in 1..=MAX_EXP_TERMS {
+        term = term * r / (FIXED_ONE * i);
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+
+    sum.checked_mul(1_u128 << n)
+}
+
+// ln(1 + t) for t in [0, FIXED_ONE], rounded down
+fn ln_1p_fixed(t: u128) -> u128 {
+    // ln(1 + t) = 2 * atanh(z) with z = t / (2 + t) <= 1/3
+    let z = t * FIXED_ONE / (2 * FIXED_ONE + t);
+    let z_squared = z * z / FIXED_ONE;
+
+    let mut power = z;
+    let mut sum = 0_u128;
+    for k in (1..).step_by(2).take(MAX_LN_TERMS) {
+        if power == 0 {
+            break;
+        }
+        sum += power / k;
+        power = power * z_squared / FIXED_ONE;
+    }
+
+    2 * sum
+}
+
+// ln(1 + e^x) for signed x in FIXED_ONE units
+fn softplus_fixed(x: i128) -> Option<u128> {
+    let magnitude = x.unsigned_abs();
+    // e^-|x|, which underflows to zero for large |x|
+    let decay = match exp_fixed(magnitude) {
+        Some(value) => FIXED_ONE * FIXED_ONE / value,
+        None => 0,
+    };
+
+    if x >= 0 {
+        magnitude.checked_add(ln_1p_fixed(decay))
+    } else {
+        Some(ln_1p_fixed(decay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: u128, expected: u128, tolerance: u128) {
+        assert!(
+            actual.abs_diff(expected) <= tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    fn state(tokens_sold: u64, real_token_reserves: u64) -> CurveState {
+        CurveState {
+            token_reserves: 0,
+            sol_reserves: 0,
+            tokens_sold,
+            real_token_reserves,
+        }
+    }
+
+    fn curves() -> Vec<Box<dyn Curve>> {
+        vec![
+            Box::new(LinearCurve {
+                base_price: 1_000,
+                slope: 10,
+            }),
+            Box::new(ExponentialCurve {
+                base_price: 1_000,
+                growth_rate: 1_000_000_000,
+            }),
+            Box::new(SigmoidCurve::new(100_000, 1_000_000_000, 500_000_000_000)),
+        ]
+    }
+
+    // Counts the cost evaluations a search makes
+    struct Counting<T: SupplyCurve> {
+        curve: T,
+        evaluations: std::cell::Cell<u32>,
+    }
+
+    impl<T: SupplyCurve> SupplyCurve for Counting<T> {
+        fn cost(&self, supply: u64) -> Option<u128> {
+            self.evaluations.set(self.evaluations.get() + 1);
+            self.curve.cost(supply)
+        }
+    }
+
+    #[test]
+    fn exp_fixed_matches_known_values() {
+        assert_eq!(exp_fixed(0), Some(FIXED_ONE));
+        assert_close(exp_fixed(LN_2).unwrap(), 2 * FIXED_ONE, 10);
+        assert_close(exp_fixed(FIXED_ONE).unwrap(), 2_718_281_828_459, 10);
+        assert_close(
+            exp_fixed(10 * FIXED_ONE).unwrap(),
+            22_026_465_794_806_718,
+            1_000_000,
+        );
+    }
+
+    #[test]
+    fn exp_fixed_overflows_to_none() {
+        assert!(exp_fixed(80 * LN_2).is_some());
+        assert_eq!(exp_fixed(100 * LN_2), None);
+        assert_eq!(exp_fixed(127 * LN_2), None);
+    }
+
+    #[test]
+    fn ln_1p_fixed_matches_known_values() {
+        assert_eq!(ln_1p_fixed(0), 0);
+        assert_close(ln_1p_fixed(FIXED_ONE), LN_2, 10);
+        assert_close(ln_1p_fixed(FIXED_ONE / 2), 405_465_108_108, 10);
+    }
+
+    #[test]
+    fn softplus_fixed_matches_known_values() {
+        let x = 3 * FIXED_ONE as i128;
+
+        assert_close(softplus_fixed(0).unwrap(), LN_2, 10);
+        // softplus(x) - softplus(-x) = x
+        assert_eq!(
+            softplus_fixed(x).unwrap() - softplus_fixed(-x).unwrap(),
+            x as u128
+        );
+        assert_close(softplus_fixed(-x).unwrap(), 48_587_351_573, 10);
+    }
+
+    #[test]
+    fn softplus_fixed_saturates_for_large_inputs() {
+        let x = 200 * FIXED_ONE as i128;
+
+        assert_eq!(softplus_fixed(x), Some(x as u128));
+        assert_eq!(softplus_fixed(-x), Some(0));
+    }
+
+    #[test]
+    fn buy_spends_at_most_the_budget() {
+        let budget = 1_000_000_000;
+        for curve in curves() {
+            let state = state(100_000_000_000, u64::MAX / 2);
+            let tokens = curve
+                .amount_out(&state, budget, TradeDirection::Buy)
+                .unwrap();
+            let cost = curve
+                .amount_in(&state, tokens, TradeDirection::Buy)
+                .unwrap();
+            let next = curve
+                .amount_in(&state, tokens + 1, TradeDirection::Buy)
+                .unwrap();
+
+            assert!(tokens > 0);
+            assert!(cost <= budget);
+            assert!(next > budget);
+        }
+    }
+
+    #[test]
+    fn buy_is_capped_at_real_reserves() {
+        for curve in curves() {
+            let state = state(100_000_000_000, 1_000);
+            let tokens = curve
+                .amount_out(&state, 1_000_000_000, TradeDirection::Buy)
+                .unwrap();
+
+            assert_eq!(tokens, 1_000);
+        }
+    }
+
+    #[test]
+    fn selling_a_buy_back_returns_its_cost() {
+        let budget = 1_000_000_000;
+        for curve in curves() {
+            let before = state(100_000_000_000, u64::MAX / 2);
+            let tokens = curve
+                .amount_out(&before, budget, TradeDirection::Buy)
+                .unwrap();
+            let cost = curve
+                .amount_in(&before, tokens, TradeDirection::Buy)
+                .unwrap();
+
+            let after = state(before.tokens_sold + tokens, u64::MAX / 2);
+            let proceeds = curve
+                .amount_out(&after, tokens, TradeDirection::Sell)
+                .unwrap();
+            assert_eq!(proceeds, cost);
+
+            // the smallest sale reaching the cost is at most the tokens bought
+            let sold = curve.amount_in(&after, cost, TradeDirection::Sell).unwrap();
+            assert!(sold <= tokens);
+            assert!(
+                curve
+                    .amount_out(&after, sold, TradeDirection::Sell)
+                    .unwrap()
+                    >= cost
+            );
+            if sold > 0 {
+                assert!(
+                    curve
+                        .amount_out(&after, sold - 1, TradeDirection::Sell)
+                        .unwrap()
+                        < cost
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn searches_are_bounded_by_the_bits_of_the_amount() {
+        let curve = Counting {
+            curve: SigmoidCurve::new(100_000, 1_000_000_000, 500_000_000_000),
+            evaluations: std::cell::Cell::new(0),
+        };
+
+        // the start plus one evaluation per bisection step
+        curve.max_buy(0, u64::MAX, u64::MAX);
+        assert!(curve.evaluations.get() <= 1 + 64);
+
+        curve.evaluations.set(0);
+        let tokens_sold = 1_000_000_000_000_000;
+        let target = curve.cost_between(0, tokens_sold).unwrap() / 2;
+        curve.evaluations.set(0);
+        curve.min_sell(tokens_sold, target).unwrap();
+        // the check against the whole supply, then two evaluations per step
+        assert!(curve.evaluations.get() <= 2 + 2 * 64);
+    }
+
+    #[test]
+    fn exp_fixed_stops_within_its_term_bound() {
+        // the largest remainder still converges before the last term
+        let r = LN_2 - 1;
+        let last = (1..=MAX_EXP_TERMS).fold(FIXED_ONE, |term, i| term * r / (FIXED_ONE * i));
+
+        assert_eq!(last, 0);
+    }
+
+    #[test]
+    fn sell_beyond_tokens_sold_fails() {
+        for curve in curves() {
+            let state = state(1_000, u64::MAX / 2);
+
+            assert!(curve
+                .amount_out(&state, 1_001, TradeDirection::Sell)
+                .is_err());
+        }
+    }
+}
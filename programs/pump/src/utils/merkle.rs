@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+// keccak(wallet || max_allocation as little-endian u64), committing a wallet to its presale cap
+pub fn allowlist_leaf(wallet: &Pubkey, max_allocation: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &max_allocation.to_le_bytes()]).to_bytes()
+}
+
+// Pairs are hashed in sorted order, so proofs carry no left/right flags
+fn hash_pair(first: &[u8; 32], second: &[u8; 32]) -> [u8; 32] {
+    if first <= second {
+        hashv(&[first, second]).to_bytes()
+    } else {
+        hashv(&[second, first]).to_bytes()
+    }
+}
+
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling))
+        == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds allowlist roots and proofs the way clients do, matching verify_proof.
+    // A node without a sibling moves up a layer unchanged.
+    struct MerkleTree {
+        layers: Vec<Vec<[u8; 32]>>,
+    }
+
+    impl MerkleTree {
+        fn new(leaves: Vec<[u8; 32]>) -> Self {
+            let mut layers = vec![leaves];
+            while layers.last().is_some_and(|layer| layer.len() > 1) {
+                let next = layers
+                    .last()
+                    .unwrap()
+                    .chunks(2)
+                    .map(|pair| match pair {
+                        [first, second] => hash_pair(first, second),
+                        [single] => *single,
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                layers.push(next);
+            }
+
+            Self { layers }
+        }
+
+        // Zeroed for a tree without leaves
+        fn root(&self) -> [u8; 32] {
+            self.layers
+                .last()
+                .and_then(|layer| layer.first())
+                .copied()
+                .unwrap_or_default()
+        }
+
+        fn proof(&self, mut index: usize) -> Vec<[u8; 32]> {
+            let mut proof = Vec::new();
+            for layer in &self.layers[..self.layers.len() - 1] {
+                if let Some(sibling) = layer.get(index ^ 1) {
+                    proof.push(*sibling);
+                }
+                index /= 2;
+            }
+
+            proof
+        }
+    }
+
+    fn wallets(count: u8) -> Vec<(Pubkey, u64)> {
+        (0..count)
+            .map(|i| {
+                (
+                    Pubkey::new_from_array([i; 32]),
+                    1_000_000_000 * (i as u64 + 1),
+                )
+            })
+            .collect()
+    }
+
+    fn tree(wallets: &[(Pubkey, u64)]) -> MerkleTree {
+        MerkleTree::new(
+            wallets
+                .iter()
+                .map(|(wallet, cap)| allowlist_leaf(wallet, *cap))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn verifies_every_leaf() {
+        // odd sizes carry a node up unchanged
+        for count in 1..=7 {
+            let wallets = wallets(count);
+            let tree = tree(&wallets);
+            for (index, (wallet, cap)) in wallets.iter().enumerate() {
+                assert!(verify_proof(
+                    &tree.proof(index),
+                    tree.root(),
+                    allowlist_leaf(wallet, *cap)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn single_leaf_is_its_own_root() {
+        let wallets = wallets(1);
+        let tree = tree(&wallets);
+
+        assert!(tree.proof(0).is_empty());
+        assert_eq!(tree.root(), allowlist_leaf(&wallets[0].0, wallets[0].1));
+    }
+
+    #[test]
+    fn rejects_a_different_cap() {
+        let wallets = wallets(4);
+        let tree = tree(&wallets);
+        let (wallet, cap) = wallets[1];
+
+        assert!(!verify_proof(
+            &tree.proof(1),
+            tree.root(),
+            allowlist_leaf(&wallet, cap + 1)
+        ));
+    }
+
+    #[test]
+    fn rejects_another_wallets_proof() {
+        let wallets = wallets(4);
+        let tree = tree(&wallets);
+        let (wallet, cap) = wallets[1];
+
+        assert!(!verify_proof(
+            &tree.proof(2),
+            tree.root(),
+            allowlist_leaf(&wallet, cap)
+        ));
+    }
+
+    #[test]
+    fn rejects_a_tampered_proof() {
+        let wallets = wallets(4);
+        let tree = tree(&wallets);
+        let (wallet, cap) = wallets[0];
+        let mut proof = tree.proof(0);
+        proof[1][0] ^= 1;
+
+        assert!(!verify_proof(
+            &proof,
+            tree.root(),
+            allowlist_leaf(&wallet, cap)
+        ));
+        assert!(!verify_proof(
+            &proof[..1],
+            tree.root(),
+            allowlist_leaf(&wallet, cap)
+        ));
+    }
+
+    #[test]
+    fn pairs_hash_in_sorted_order() {
+        let (first, second) = ([1; 32], [2; 32]);
+
+        assert_eq!(hash_pair(&first, &second), hash_pair(&second, &first));
+    }
+}
@@ -1,2 +1,8 @@
 pub mod calc;
+pub mod cpi;
+pub mod curve;
+pub mod merkle;
 pub use calc::*;
+pub use cpi::*;
+pub use curve::*;
+pub use merkle::*;
@@ -0,0 +1,20 @@
+#!/bin/bash
+
+# Fetches the mainnet programs the tests load at genesis. Run from the repo root;
+# `yarn test` runs it before anchor test. The dumps are not committed, so only
+# missing files are downloaded unless --refresh is passed.
+
+set -e
+
+dump() {
+  if [ "$REFRESH" = 1 ] || [ ! -f "$2" ]; then
+    solana program dump -u m "$1" "$2"
+  fi
+}
+
+REFRESH=0
+if [ "$1" = "--refresh" ]; then
+  REFRESH=1
+fi
+
+dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s tests/fixtures/mpl_token_metadata.so
@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
+};
+
+// Stand-in for Meteora's dynamic AMM program, deployed at its address in the local test validator.
+// `initialize_permissionless_pool` takes the same accounts and arguments as the real instruction
+// so the pump program's migration CPI can be exercised without a mainnet fork. The real program
+// parks liquidity in Meteora vaults; the mock keeps it in plain token accounts owned by the
+// pool, which also signs for the LP mint.
+declare_id!("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB");
+
+pub const LP_MINT_SEED: &str = "lp_mint";
+pub const VAULT_SEED: &str = "vault";
+
+#[program]
+pub mod mock_meteora_amm {
+    use super::*;
+
+    pub fn initialize_permissionless_pool(
+        ctx: Context<InitializePermissionlessPool>,
+        curve_type: CurveType,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<()> {
+        require_keys_neq!(
+            ctx.accounts.token_a_mint.key(),
+            ctx.accounts.token_b_mint.key()
+        );
+
+        for (from, to, amount) in [
+            (
+                &ctx.accounts.payer_token_a,
+                &ctx.accounts.a_vault,
+                token_a_amount,
+            ),
+            (
+                &ctx.accounts.payer_token_b,
+                &ctx.accounts.b_vault,
+                token_b_amount,
+            ),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        let (first_key, second_key) = sorted_keys(
+            ctx.accounts.token_a_mint.key(),
+            ctx.accounts.token_b_mint.key(),
+        );
+        let liquidity = integer_sqrt(token_a_amount as u128 * token_b_amount as u128);
+        require!(liquidity > 0, MockError::InitLiquidityTooSmall);
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.payer_pool_lp.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&[first_key.as_ref(), second_key.as_ref(), &[ctx.bumps.pool]]],
+            ),
+            liquidity,
+        )?;
+
+        ctx.accounts.pool.set_inner(Pool {
+            lp_mint: ctx.accounts.lp_mint.key(),
+            token_a_mint: ctx.accounts.token_a_mint.key(),
+            token_b_mint: ctx.accounts.token_b_mint.key(),
+            curve_type,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveType {
+    ConstantProduct,
+    Stable { amp: u64 },
+}
+
+#[account]
+pub struct Pool {
+    pub lp_mint: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub curve_type: CurveType,
+}
+
+impl Pool {
+    // Discriminator (8) + 3 mints (3 * 32) + curve type (1 + 8)
+    pub const ACCOUNT_SIZE: usize = 8 + 3 * 32 + 1 + 8;
+}
+
+// Meteora keys a permissionless pool by its two mints, larger key first
+pub fn sorted_keys(mint_a: Pubkey, mint_b: Pubkey) -> (Pubkey, Pubkey) {
+    if mint_a > mint_b {
+        (mint_a, mint_b)
+    } else {
+        (mint_b, mint_a)
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializePermissionlessPool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::ACCOUNT_SIZE,
+        seeds = [
+            sorted_keys(token_a_mint.key(), token_b_mint.key()).0.as_ref(),
+            sorted_keys(token_a_mint.key(), token_b_mint.key()).1.as_ref(),
+        ],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [LP_MINT_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+        mint::decimals = 9,
+        mint::authority = pool,
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED.as_bytes(), token_a_mint.key().as_ref(), pool.key().as_ref()],
+        bump,
+        token::mint = token_a_mint,
+        token::authority = pool,
+    )]
+    pub a_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED.as_bytes(), token_b_mint.key().as_ref(), pool.key().as_ref()],
+        bump,
+        token::mint = token_b_mint,
+        token::authority = pool,
+    )]
+    pub b_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the mock has no vault LP
+    #[account(mut)]
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: the mock has no vault LP
+    #[account(mut)]
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: the mock has no vault LP
+    #[account(mut)]
+    pub a_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: the mock has no vault LP
+    #[account(mut)]
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = payer,
+    )]
+    pub payer_token_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = payer,
+    )]
+    pub payer_token_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = payer,
+    )]
+    pub payer_pool_lp: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the mock charges no protocol fee
+    #[account(mut)]
+    pub protocol_token_a_fee: UncheckedAccount<'info>,
+
+    /// CHECK: the mock charges no protocol fee
+    #[account(mut)]
+    pub protocol_token_b_fee: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: the mock charges no protocol fee
+    pub fee_owner: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: the mock writes no LP metadata
+    #[account(mut)]
+    pub mint_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: the mock writes no LP metadata
+    pub metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: the mock has no vault program
+    pub vault_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[error_code]
+pub enum MockError {
+    #[msg("Initial liquidity is zero")]
+    InitLiquidityTooSmall,
+}
+
+fn integer_sqrt(value: u128) -> u64 {
+    if value < 2 {
+        return value as u64;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x as u64
+}
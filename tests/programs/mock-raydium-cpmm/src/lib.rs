@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
+};
+
+// Stand-in for Raydium's CPMM program, deployed at its address in the local test validator.
+// `initialize` takes the same accounts and arguments as the real instruction so the pump
+// program's migration CPI can be exercised without a mainnet fork.
+declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+pub const AUTH_SEED: &str = "vault_and_lp_mint_auth_seed";
+pub const POOL_SEED: &str = "pool";
+pub const POOL_LP_MINT_SEED: &str = "pool_lp_mint";
+pub const POOL_VAULT_SEED: &str = "pool_vault";
+
+// LP kept out of circulation on creation, as Raydium does
+pub const LOCKED_LIQUIDITY: u64 = 100;
+
+#[program]
+pub mod mock_raydium_cpmm {
+    use super::*;
+
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        init_amount_0: u64,
+        init_amount_1: u64,
+        open_time: u64,
+    ) -> Result<()> {
+        require_keys_neq!(
+            ctx.accounts.token_0_mint.key(),
+            ctx.accounts.token_1_mint.key()
+        );
+        require!(
+            ctx.accounts.token_0_mint.key() < ctx.accounts.token_1_mint.key(),
+            MockError::InvalidTokenOrder
+        );
+
+        for (from, to, amount) in [
+            (
+                &ctx.accounts.creator_token_0,
+                &ctx.accounts.token_0_vault,
+                init_amount_0,
+            ),
+            (
+                &ctx.accounts.creator_token_1,
+                &ctx.accounts.token_1_vault,
+                init_amount_1,
+            ),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: ctx.accounts.creator.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        let liquidity = integer_sqrt(init_amount_0 as u128 * init_amount_1 as u128);
+        let lp_amount = liquidity
+            .checked_sub(LOCKED_LIQUIDITY)
+            .ok_or(MockError::InitLiquidityTooSmall)?;
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.creator_lp_token.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                &[&[AUTH_SEED.as_bytes(), &[ctx.bumps.authority]]],
+            ),
+            lp_amount,
+        )?;
+
+        ctx.accounts.pool_state.set_inner(PoolState {
+            token_0_mint: ctx.accounts.token_0_mint.key(),
+            token_1_mint: ctx.accounts.token_1_mint.key(),
+            lp_mint: ctx.accounts.lp_mint.key(),
+            lp_supply: liquidity,
+            open_time,
+        });
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct PoolState {
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_supply: u64,
+    pub open_time: u64,
+}
+
+impl PoolState {
+    // Discriminator (8) + 3 mints (3 * 32) + LP supply (8) + open time (8)
+    pub const ACCOUNT_SIZE: usize = 8 + 3 * 32 + 8 + 8;
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: the mock has a single fee tier and never reads it
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: signs for the vaults and the LP mint
+    #[account(seeds = [AUTH_SEED.as_bytes()], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = PoolState::ACCOUNT_SIZE,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            token_0_mint.key().as_ref(),
+            token_1_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub pool_state: Box<Account<'info, PoolState>>,
+
+    pub token_0_mint: Box<Account<'info, Mint>>,
+    pub token_1_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [POOL_LP_MINT_SEED.as_bytes(), pool_state.key().as_ref()],
+        bump,
+        mint::decimals = 9,
+        mint::authority = authority,
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = token_0_mint,
+        token::authority = creator,
+    )]
+    pub creator_token_0: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_1_mint,
+        token::authority = creator,
+    )]
+    pub creator_token_1: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_lp_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            POOL_VAULT_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            token_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = token_0_mint,
+        token::authority = authority,
+    )]
+    pub token_0_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            POOL_VAULT_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            token_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = token_1_mint,
+        token::authority = authority,
+    )]
+    pub token_1_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the mock charges no pool creation fee
+    #[account(mut)]
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    /// CHECK: the mock keeps no price observations
+    #[account(mut)]
+    pub observation_state: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_0_program: Program<'info, Token>,
+    pub token_1_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[error_code]
+pub enum MockError {
+    #[msg("token_0_mint must sort before token_1_mint")]
+    InvalidTokenOrder,
+
+    #[msg("Initial liquidity does not cover the locked amount")]
+    InitLiquidityTooSmall,
+}
+
+fn integer_sqrt(value: u128) -> u64 {
+    if value < 2 {
+        return value as u64;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x as u64
+}
@@ -9,16 +9,22 @@ import {
   sendAndConfirmTransaction,
   ComputeBudgetProgram,
   SYSVAR_RENT_PUBKEY,
+  SYSVAR_CLOCK_PUBKEY,
 } from "@solana/web3.js";
 import {
   createMint,
   getOrCreateAssociatedTokenAccount,
   mintTo,
   getAssociatedTokenAddress,
+  transfer,
+  getAccount,
+  getMint,
+  NATIVE_MINT,
 } from "@solana/spl-token";
 import { BN } from "bn.js";
 import key1 from "./keys/user1.json";
 import key2 from "./keys/user2.json";
+import deployerKey from "../id.json";
 import {
   ASSOCIATED_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
@@ -26,6 +32,7 @@ import {
 import { simulateTransaction } from "@coral-xyz/anchor/dist/cjs/utils/rpc";
 import { assert, expect } from "chai";
 import { execSync } from "child_process";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 anchor.setProvider(anchor.AnchorProvider.env());
 
@@ -33,6 +40,36 @@ const connection = anchor.getProvider().connection;
 const curveSeed = "CurveConfiguration";
 const POOL_SEED_PREFIX = "liquidity_pool";
 const LP_SEED_PREFIX = "LiqudityProvider";
+const SOL_VAULT_SEED_PREFIX = "sol_vault";
+const PRESALE_BID_SEED_PREFIX = "presale_bid";
+const BATCH_ORDER_SEED_PREFIX = "batch_order";
+const PROPOSAL_SEED_PREFIX = "proposal";
+const PENDING_UPDATE_SEED_PREFIX = "pending_update";
+const INITIAL_VIRTUAL_SOL_RESERVES = new BN(30_000_000_000);
+const INITIAL_VIRTUAL_TOKEN_RESERVES = new BN(100_000_000_000_000);
+const PRICE_SCALE = new BN(1_000_000_000);
+const CONSTANT_PRODUCT_PARAMS = [new BN(0), new BN(0), new BN(0)];
+const COMPLETION_TOKENS_SOLD = new BN(500_000_000_000_000);
+// seconds a configuration update waits before it can execute
+const MIN_UPDATE_DELAY = new BN(2);
+// served by tests/programs/mock-raydium-cpmm in the local validator
+const RAYDIUM_CPMM_PROGRAM_ID = new PublicKey(
+  "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"
+);
+// fee tier completed pools migrate into, set on the configuration
+const RAYDIUM_AMM_CONFIG = getRaydiumAmmConfig(0);
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+const TOKEN_TOTAL_SUPPLY = new BN(1_000_000_000_000_000);
+// loaded at genesis from tests/fixtures, see Anchor.toml
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+// served by tests/programs/mock-meteora-amm in the local validator
+const METEORA_AMM_PROGRAM_ID = new PublicKey(
+  "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB"
+);
 
 describe("pump", () => {
   const program = anchor.workspace.Pump as Program<Pump>;
@@ -40,6 +77,14 @@ describe("pump", () => {
   // custom setting
   const user = Keypair.fromSecretKey(new Uint8Array(key1));
   const user2 = Keypair.fromSecretKey(new Uint8Array(key2));
+  // collects the fees once the admin points the configuration at it
+  const treasury = Keypair.generate();
+  // deploys the program, so it is the upgrade authority
+  const deployer = Keypair.fromSecretKey(new Uint8Array(deployerKey));
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
   const tokenDecimal = 6;
   const amount = new BN(1000000000).mul(new BN(10 ** tokenDecimal));
   const airdropAmount = 10 ** 11;
@@ -53,6 +98,8 @@ describe("pump", () => {
   let mint2: PublicKey;
   let tokenAta2: PublicKey;
 
+  let completedLaunch: Awaited<ReturnType<typeof launchPool>>;
+
   console.log("Admin's wallet address is : ", user.publicKey.toBase58());
 
   it("Airdrop to admin wallet", async () => {
@@ -61,6 +108,8 @@ describe("pump", () => {
     );
     await airdrop(user.publicKey, airdropAmount);
     await airdrop(user2.publicKey, airdropAmount);
+    // funded, so that fees below the rent-exempt minimum can land
+    await airdrop(treasury.publicKey, 10 ** 9);
     const adminBalance =
       (await connection.getBalance(user.publicKey)) / 10 ** 9;
     console.log("admin wallet balance : ", adminBalance, "SOL");
@@ -146,6 +195,72 @@ describe("pump", () => {
     console.log(res.toString());
   });
 
+  it("Reject fees above the maximum", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    try {
+      await program.methods
+        .initialize(
+          1001,
+          100,
+          new BN(0),
+          new BN(0),
+          { disabled: {} },
+          new BN(0),
+          RAYDIUM_AMM_CONFIG
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          program: program.programId,
+          programData,
+          authority: deployer.publicKey,
+          admin: user.publicKey,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([deployer, user])
+        .rpc();
+      assert.fail("initialize should reject a fee above the maximum");
+    } catch (error) {
+      expect(String(error)).to.contain("InvalidFee");
+    }
+  });
+
+  it("Reject initialization by anyone but the upgrade authority", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    try {
+      await program.methods
+        .initialize(
+          100,
+          100,
+          INITIAL_VIRTUAL_SOL_RESERVES,
+          INITIAL_VIRTUAL_TOKEN_RESERVES,
+          { tokensSold: { amount: COMPLETION_TOKENS_SOLD } },
+          MIN_UPDATE_DELAY,
+          RAYDIUM_AMM_CONFIG
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          program: program.programId,
+          programData,
+          authority: user2.publicKey,
+          admin: user2.publicKey,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+      assert.fail("only the upgrade authority should initialize");
+    } catch (error) {
+      expect(String(error)).to.contain("NotUpgradeAuthority");
+    }
+  });
+
   it("Initialize the contract", async () => {
     console.log("program id: ", program.programId.toBase58());
     const [curveConfig] = PublicKey.findProgramAddressSync(
@@ -153,12 +268,23 @@ describe("pump", () => {
       program.programId
     );
     const tx = new Transaction().add(
-      ComputeBudgetProgram.setComputeUnitLimit({ units: 10_000 }),
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 50_000 }),
       ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 1200_000 }),
       await program.methods
-        .initialize(1)
+        .initialize(
+          100,
+          100,
+          INITIAL_VIRTUAL_SOL_RESERVES,
+          INITIAL_VIRTUAL_TOKEN_RESERVES,
+          { tokensSold: { amount: COMPLETION_TOKENS_SOLD } },
+          MIN_UPDATE_DELAY,
+          RAYDIUM_AMM_CONFIG
+        )
         .accounts({
           dexConfigurationAccount: curveConfig,
+          program: program.programId,
+          programData,
+          authority: deployer.publicKey,
           admin: user.publicKey,
           rent: SYSVAR_RENT_PUBKEY,
           systemProgram: SystemProgram.programId,
@@ -168,257 +294,3488 @@ describe("pump", () => {
     tx.feePayer = user.publicKey;
     tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
     // console.log(await connection.simulateTransaction(tx))
-    const sig = await sendAndConfirmTransaction(connection, tx, [user], {
-      skipPreflight: true,
-    });
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      tx,
+      [user, deployer],
+      { skipPreflight: true }
+    );
     console.log("Successfully initialized : ", sig);
     let pool = await program.account.curveConfiguration.fetch(curveConfig);
     console.log("Pool State : ", pool);
   });
 
-  // it('create pool', async () => {
-  //   try {
-  //     const [poolPda] = PublicKey.findProgramAddressSync([Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer(), mint2.toBuffer()], program.programId);
-  //     const [liquidityProviderAccount] = PublicKey.findProgramAddressSync([Buffer.from(LP_SEED_PREFIX), poolPda.toBuffer(), user.publicKey.toBuffer()], program.programId);
-  //     const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
-  //     const poolTokenTwo = await getAssociatedTokenAddress(mint2, poolPda, true);
-  //     const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
-  //     const userAta2 = await getAssociatedTokenAddress(mint2, user.publicKey);
-
-  //     const tx = new Transaction().add(
-  //       ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
-  //       ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
-  //       await program.methods
-  //         .createPool()
-  //         .accounts({
-  //           pool: poolPda,
-  //           mintTokenOne: mint1,
-  //           mintTokenTwo: mint2,
-  //           poolTokenAccountOne: poolTokenOne,
-  //           poolTokenAccountTwo: poolTokenTwo,
-  //           payer: user.publicKey,
-  //           tokenProgram: TOKEN_PROGRAM_ID,
-  //           rent: SYSVAR_RENT_PUBKEY,
-  //           associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
-  //           systemProgram: SystemProgram.programId
-  //         })
-  //         .instruction()
-  //     );
-  //     tx.feePayer = user.publicKey;
-  //     tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-  //     // console.log(await connection.simulateTransaction(tx))
-  //     const sig = await sendAndConfirmTransaction(connection, tx, [user], { skipPreflight: true });
-  //     console.log('Successfully created pool : ', sig);
-  //   } catch (error) {
-  //     console.log('Error in creating pool', error);
-  //   }
-  // });
+  it("Update the configuration as admin only, after the delay", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [pendingUpdate] = PublicKey.findProgramAddressSync(
+      [Buffer.from(PENDING_UPDATE_SEED_PREFIX)],
+      program.programId
+    );
+    const updateConfig = (admin: Keypair, sellFeeBps: number) =>
+      program.methods
+        .updateConfig(
+          100,
+          sellFeeBps,
+          INITIAL_VIRTUAL_SOL_RESERVES,
+          INITIAL_VIRTUAL_TOKEN_RESERVES,
+          { tokensSold: { amount: COMPLETION_TOKENS_SOLD } },
+          treasury.publicKey,
+          RAYDIUM_AMM_CONFIG
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pendingUpdate,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    const executeConfigUpdate = () =>
+      program.methods
+        .executeConfigUpdate()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pendingUpdate,
+          payer: user.publicKey,
+        })
+        .rpc();
+    const cancelConfigUpdate = (admin: Keypair) =>
+      program.methods
+        .cancelConfigUpdate()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pendingUpdate,
+          payer: user.publicKey,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    const initial = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(initial.admin.equals(user.publicKey));
+    assert.isTrue(initial.treasury.equals(user.publicKey));
+    assert.strictEqual(initial.sellFeeBps, 100);
+    assert.isTrue(initial.minUpdateDelay.eq(MIN_UPDATE_DELAY));
+    assert.isTrue(initial.raydiumAmmConfig.equals(RAYDIUM_AMM_CONFIG));
 
-  it("add liquidity", async () => {
     try {
-      const [poolPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
-        program.programId
-      );
+      await updateConfig(user2, 200);
+      assert.fail("only the admin should update the configuration");
+    } catch (error) {
+      expect(String(error)).to.contain("ConstraintHasOne");
+    }
 
-      const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from(LP_SEED_PREFIX),
-          poolPda.toBuffer(),
-          user.publicKey.toBuffer(),
-        ],
-        program.programId
-      );
-      const poolTokenOne = await getAssociatedTokenAddress(
-        mint1,
-        poolPda,
-        true
-      );
-      const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+    // a cancelled update never applies
+    await updateConfig(user, 200);
+    try {
+      await cancelConfigUpdate(user2);
+      assert.fail("only the admin should cancel an update");
+    } catch (error) {
+      expect(String(error)).to.contain("ConstraintHasOne");
+    }
+    await cancelConfigUpdate(user);
+    assert.isNull(await connection.getAccountInfo(pendingUpdate));
 
-      const tx = new Transaction().add(
-        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
-        ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
-        await program.methods
-          .addLiquidity(new BN(1000000000000000), new BN(30000000000))
-          .accounts({
-            pool: poolPda,
-            mintTokenOne: mint1,
-            poolTokenAccountOne: poolTokenOne,
-            userTokenAccountOne: userAta1,
-            liquidityProviderAccount: liquidityProviderAccount,
-            user: user.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
-            rent: SYSVAR_RENT_PUBKEY,
-            systemProgram: SystemProgram.programId,
-          })
-          .instruction()
-      );
-      tx.feePayer = user.publicKey;
-      tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      // console.log(await connection.simulateTransaction(tx))
-      const sig = await sendAndConfirmTransaction(connection, tx, [user], {
-        skipPreflight: true,
-      });
-      console.log("Successfully added liquidity : ", sig);
-
-      const signature = await connection.requestAirdrop(poolPda, 10 ** 9);
-      // 2 - Fetch the latest blockhash
-      const { blockhash, lastValidBlockHeight } =
-        await connection.getLatestBlockhash();
-      // 3 - Confirm transaction success
-      await connection.confirmTransaction({
-        blockhash,
-        lastValidBlockHeight,
-        signature,
-      });
+    // queued values wait out the delay, then anyone applies them
+    await updateConfig(user, 200);
+    try {
+      await executeConfigUpdate();
+      assert.fail("the update should wait for its delay");
     } catch (error) {
-      console.log("Error in adding liquidity", error);
+      expect(String(error)).to.contain("UpdateNotEffective");
     }
+    let config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.strictEqual(config.sellFeeBps, 100);
+    assert.isTrue(config.treasury.equals(user.publicKey));
+
+    const { effectiveAt } =
+      await program.account.pendingUpdate.fetch(pendingUpdate);
+    await waitForTimestamp(effectiveAt);
+    await executeConfigUpdate();
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.admin.equals(user.publicKey));
+    assert.strictEqual(config.sellFeeBps, 200);
+    assert.isTrue(config.treasury.equals(treasury.publicKey));
+    assert.isNull(await connection.getAccountInfo(pendingUpdate));
   });
 
-  it("Swap token", async () => {
-    try {
-      const [curveConfig] = PublicKey.findProgramAddressSync(
-        [Buffer.from(curveSeed)],
-        program.programId
-      );
-      const [poolPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
-        program.programId
-      );
-      const poolTokenOne = await getAssociatedTokenAddress(
-        mint1,
-        poolPda,
-        true
-      );
-      const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
-
-      console.log(
-        "pooltoken one",
-        poolTokenOne.toBase58(),
-        "program id",
-        program.programId.toBase58(),
-        "userAta1",
-        userAta1.toBase58()
-      );
+  it("Hand the admin over in two steps", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const proposeAdmin = (admin: Keypair, newAdmin: PublicKey) =>
+      program.methods
+        .proposeAdmin(newAdmin)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    const acceptAdmin = (newAdmin: Keypair) =>
+      program.methods
+        .acceptAdmin()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          newAdmin: newAdmin.publicKey,
+        })
+        .signers([newAdmin])
+        .rpc();
 
-      const tx = new Transaction().add(
-        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
-        ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
-        await program.methods
-          .swap(new BN(200000000), new BN(2))
-          .accounts({
-            pool: poolPda,
-            mintTokenOne: mint1,
-            poolTokenAccountOne: poolTokenOne,
-            userTokenAccountOne: userAta1,
-            dexConfigurationAccount: curveConfig,
-            user: user.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
-            rent: SYSVAR_RENT_PUBKEY,
-            systemProgram: SystemProgram.programId,
-          })
-          .instruction()
-      );
-      tx.feePayer = user.publicKey;
-      tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      console.log("recentBlockhash", tx.recentBlockhash);
-      console.log("simulate", await simulateTransaction(connection, tx));
-      const sig = await sendAndConfirmTransaction(connection, tx, [user], {
-        skipPreflight: true,
-      });
+    // a cancelled proposal cannot be accepted
+    await proposeAdmin(user, user2.publicKey);
+    await program.methods
+      .cancelAdminProposal()
+      .accounts({ dexConfigurationAccount: curveConfig, admin: user.publicKey })
+      .signers([user])
+      .rpc();
+    try {
+      await acceptAdmin(user2);
+      assert.fail("nothing should be left to accept");
+    } catch (error) {
+      expect(String(error)).to.contain("NoPendingAdmin");
+    }
 
-      console.log("Successfully swapped : ", sig);
+    // control only moves once the proposed key signs
+    await proposeAdmin(user, user2.publicKey);
+    let config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.admin.equals(user.publicKey));
+    assert.isTrue(config.pendingAdmin.equals(user2.publicKey));
+    try {
+      await acceptAdmin(user);
+      assert.fail("only the proposed key should accept");
     } catch (error) {
-      console.log("Error in swap transaction", error);
+      expect(String(error)).to.contain("NotPendingAdmin");
     }
+
+    const signature = await acceptAdmin(user2);
+    await connection.confirmTransaction(signature, "confirmed");
+    const transaction = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+    });
+    const eventParser = new anchor.EventParser(
+      program.programId,
+      program.coder
+    );
+    const events = [...eventParser.parseLogs(transaction.meta.logMessages)];
+    assert.strictEqual(events[0].name, "AdminAccepted");
+    assert.isTrue(events[0].data.previousAdmin.equals(user.publicKey));
+    assert.isTrue(events[0].data.admin.equals(user2.publicKey));
+
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.admin.equals(user2.publicKey));
+    assert.isNull(config.pendingAdmin);
+
+    // hand it back for the remaining tests
+    await proposeAdmin(user2, user.publicKey);
+    await acceptAdmin(user);
   });
 
-  it("Remove liquidity", async () => {
+  it("create pool", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+
+    // only the mint authority or the admin picks how a mint launches
     try {
-      const [poolPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
-        program.programId
-      );
-      const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from(LP_SEED_PREFIX),
-          poolPda.toBuffer(),
-          user.publicKey.toBuffer(),
-        ],
-        program.programId
-      );
-      const poolTokenOne = await getAssociatedTokenAddress(
-        mint1,
-        poolPda,
-        true
-      );
-      const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
-      const tx = new Transaction().add(
-        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
-        ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
-        await program.methods
-          .removeLiquidity(new BN(10))
-          .accounts({
-            pool: poolPda,
-            mintTokenOne: mint1,
-            poolTokenAccountOne: poolTokenOne,
-            userTokenAccountOne: userAta1,
-            liquidityProviderAccount: liquidityProviderAccount,
-            user: user.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
-            rent: SYSVAR_RENT_PUBKEY,
-            systemProgram: SystemProgram.programId,
-          })
-          .instruction()
-      );
-      tx.feePayer = user.publicKey;
-      tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      console.log(await connection.simulateTransaction(tx));
-      const sig = await sendAndConfirmTransaction(connection, tx, [user], {
-        skipPreflight: true,
-      });
-      console.log("Successfully Removed liquidity : ", sig);
+      await program.methods
+        .createPool(
+          { constantProduct: {} },
+          CONSTANT_PRODUCT_PARAMS,
+          { raydium: {} },
+          { instant: {} }
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          mintTokenOne: mint1,
+          poolTokenAccountOne: poolTokenOne,
+          payer: user2.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+      assert.fail("only the mint authority should create the pool");
     } catch (error) {
-      console.log("Error in removing liquidity", error);
+      expect(String(error)).to.contain("UnauthorizedPoolCreator");
     }
+
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
+      ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
+      await program.methods
+        .createPool(
+          { constantProduct: {} },
+          CONSTANT_PRODUCT_PARAMS,
+          { raydium: {} },
+          { instant: {} }
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          mintTokenOne: mint1,
+          poolTokenAccountOne: poolTokenOne,
+          payer: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    tx.feePayer = user.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    const sig = await sendAndConfirmTransaction(connection, tx, [user], {
+      skipPreflight: true,
+    });
+    console.log("Successfully created pool : ", sig);
+
+    const pool = await program.account.liquidityPool.fetch(poolPda);
+    assert.isTrue(pool.tokenOne.equals(mint1));
+    assert.strictEqual(pool.totalSupply.toString(), "0");
+    assert.strictEqual(
+      pool.virtualSolReserves.toString(),
+      INITIAL_VIRTUAL_SOL_RESERVES.toString()
+    );
+    assert.strictEqual(
+      pool.virtualTokenReserves.toString(),
+      INITIAL_VIRTUAL_TOKEN_RESERVES.toString()
+    );
   });
-});
 
-function comparePublicKeys(pubkey1: PublicKey, pubkey2: PublicKey): number {
-  const key1Bytes = pubkey1.toBuffer();
-  const key2Bytes = pubkey2.toBuffer();
+  it("add liquidity", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
 
-  for (let i = 0; i < key1Bytes.length; i++) {
-    if (key1Bytes[i] > key2Bytes[i]) {
-      return 1;
-    } else if (key1Bytes[i] < key2Bytes[i]) {
-      return -1;
-    }
-  }
-  return 0;
-}
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        poolPda.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
 
-function generateSeed(tokenOne: PublicKey, tokenTwo: PublicKey): string {
-  return comparePublicKeys(tokenOne, tokenTwo) > 0
-    ? `${tokenOne.toString()}${tokenTwo.toString()}`
-    : `${tokenTwo.toString()}${tokenOne.toString()}`;
-}
+    const amountOne = new BN(1000000000000000);
+    const amountTwo = new BN(30000000000);
 
-async function airdrop(publicKey: PublicKey, amount: number) {
-  // 1 - Request Airdrop
-  const signature = await connection.requestAirdrop(publicKey, amount);
-  // 2 - Fetch the latest blockhash
-  const { blockhash, lastValidBlockHeight } =
-    await connection.getLatestBlockhash();
-  // 3 - Confirm transaction success
-  await connection.confirmTransaction({
-    blockhash,
-    lastValidBlockHeight,
-    signature,
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
+      ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
+      await program.methods
+        .addLiquidity(amountOne, amountTwo)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          mintTokenOne: mint1,
+          poolTokenAccountOne: poolTokenOne,
+          userTokenAccountOne: userAta1,
+          liquidityProviderAccount: liquidityProviderAccount,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    tx.feePayer = user.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    // console.log(await connection.simulateTransaction(tx))
+    const sig = await sendAndConfirmTransaction(connection, tx, [user], {
+      skipPreflight: true,
+    });
+    console.log("Successfully added liquidity : ", sig);
+
+    const pool = await program.account.liquidityPool.fetch(poolPda);
+    const provider = await program.account.liquidityProvider.fetch(
+      liquidityProviderAccount
+    );
+    assert.strictEqual(pool.reserveOne.toString(), amountOne.toString());
+    assert.strictEqual(pool.reserveTwo.toString(), amountTwo.toString());
+    assert.isTrue(provider.shares.gtn(0));
+    assert.strictEqual(provider.shares.toString(), pool.totalSupply.toString());
   });
-}
+
+  it("add liquidity from a second provider", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        poolPda.toBuffer(),
+        user2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+    const user2Ata1 = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user2,
+        mint1,
+        user2.publicKey
+      )
+    ).address;
+    const amountOne = new BN(1000000000000);
+    await transfer(
+      connection,
+      user,
+      userAta1,
+      user2Ata1,
+      user.publicKey,
+      BigInt(amountOne.toString())
+    );
+
+    const poolBefore = await program.account.liquidityPool.fetch(poolPda);
+    const amountTwo = amountOne
+      .mul(poolBefore.reserveTwo)
+      .div(poolBefore.reserveOne);
+
+    await program.methods
+      .addLiquidity(amountOne, amountTwo)
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: poolPda,
+        solVault: getSolVault(poolPda, program.programId),
+        mintTokenOne: mint1,
+        poolTokenAccountOne: poolTokenOne,
+        userTokenAccountOne: user2Ata1,
+        liquidityProviderAccount: liquidityProviderAccount,
+        user: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user2])
+      .rpc();
+
+    const poolAfter = await program.account.liquidityPool.fetch(poolPda);
+    const provider = await program.account.liquidityProvider.fetch(
+      liquidityProviderAccount
+    );
+    assert.isTrue(provider.shares.gtn(0));
+    assert.strictEqual(
+      poolAfter.totalSupply.sub(poolBefore.totalSupply).toString(),
+      provider.shares.toString()
+    );
+    assert.strictEqual(
+      poolAfter.reserveOne.sub(poolBefore.reserveOne).toString(),
+      amountOne.toString()
+    );
+  });
+
+  it("Swap token", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+
+    console.log(
+      "pooltoken one",
+      poolTokenOne.toBase58(),
+      "program id",
+      program.programId.toBase58(),
+      "userAta1",
+      userAta1.toBase58()
+    );
+
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const poolBefore = await program.account.liquidityPool.fetch(poolPda);
+    const amountIn = new BN(200000000);
+    const feeBps = new BN(config.buyFeeBps);
+    const [tokenReserves, solReserves] = getEffectiveReserves(poolBefore);
+    const expectedOut = getAmountOut(
+      amountIn.sub(getFee(amountIn, feeBps)),
+      solReserves,
+      tokenReserves
+    );
+
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
+      ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
+      await program.methods
+        .swap(amountIn, { buy: {} }, expectedOut)
+        .accounts({
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          treasury: treasury.publicKey,
+          mintTokenOne: mint1,
+          poolTokenAccountOne: poolTokenOne,
+          userTokenAccountOne: userAta1,
+          dexConfigurationAccount: curveConfig,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    tx.feePayer = user.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    console.log("recentBlockhash", tx.recentBlockhash);
+    console.log("simulate", await simulateTransaction(connection, tx));
+    const treasuryBefore = await connection.getBalance(treasury.publicKey);
+    const sig = await sendAndConfirmTransaction(connection, tx, [user], {
+      skipPreflight: true,
+    });
+
+    console.log("Successfully swapped : ", sig);
+
+    // the fee goes to the treasury, the rest into the reserves
+    const fee = getFee(amountIn, feeBps);
+    const poolAfter = await program.account.liquidityPool.fetch(poolPda);
+    assert.strictEqual(
+      poolBefore.reserveOne.sub(poolAfter.reserveOne).toString(),
+      expectedOut.toString()
+    );
+    assert.strictEqual(
+      poolAfter.reserveTwo.sub(poolBefore.reserveTwo).toString(),
+      amountIn.sub(fee).toString()
+    );
+    assert.strictEqual(
+      (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+      fee.toNumber()
+    );
+  });
+
+  it("Reject swap below the minimum output", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+
+    const pool = await program.account.liquidityPool.fetch(poolPda);
+    const amountIn = new BN(200000000);
+    // without fees the output can never reach the raw quote
+    const [tokenReserves, solReserves] = getEffectiveReserves(pool);
+    const minAmountOut = getAmountOut(amountIn, solReserves, tokenReserves);
+
+    try {
+      await program.methods
+        .swap(amountIn, { buy: {} }, minAmountOut.addn(1))
+        .accounts({
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          treasury: treasury.publicKey,
+          mintTokenOne: mint1,
+          poolTokenAccountOne: poolTokenOne,
+          userTokenAccountOne: userAta1,
+          dexConfigurationAccount: curveConfig,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("swap should enforce the minimum output");
+    } catch (error) {
+      expect(String(error)).to.contain("SlippageExceeded");
+    }
+  });
+
+  it("Reject unknown swap direction", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+
+    const ix = await program.methods
+      .swap(new BN(200000000), { buy: {} }, new BN(0))
+      .accounts({
+        pool: poolPda,
+        solVault: getSolVault(poolPda, program.programId),
+        treasury: treasury.publicKey,
+        mintTokenOne: mint1,
+        poolTokenAccountOne: poolTokenOne,
+        userTokenAccountOne: userAta1,
+        dexConfigurationAccount: curveConfig,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    // discriminator (8) + amount (8), then the direction tag
+    ix.data[16] = 2;
+
+    const tx = new Transaction().add(ix);
+    tx.feePayer = user.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    tx.sign(user);
+    const simulation = await connection.simulateTransaction(tx);
+    assert.isNotNull(simulation.value.err);
+    expect(simulation.value.logs.join("\n")).to.contain(
+      "InvalidTradeDirection"
+    );
+  });
+
+  it("Swap exact out", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const pool = await program.account.liquidityPool.fetch(poolPda);
+    const amountOut = new BN(1000000);
+    const [tokenReserves, solReserves] = getEffectiveReserves(pool);
+    const maxAmountIn = getAmountBeforeFee(
+      getAmountIn(amountOut, solReserves, tokenReserves),
+      new BN(config.buyFeeBps)
+    );
+    const balanceBefore = await connection.getTokenAccountBalance(userAta1);
+
+    await program.methods
+      .swapExactOut(amountOut, { buy: {} }, maxAmountIn)
+      .accounts({
+        pool: poolPda,
+        solVault: getSolVault(poolPda, program.programId),
+        treasury: treasury.publicKey,
+        mintTokenOne: mint1,
+        poolTokenAccountOne: poolTokenOne,
+        userTokenAccountOne: userAta1,
+        dexConfigurationAccount: curveConfig,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const balanceAfter = await connection.getTokenAccountBalance(userAta1);
+    assert.strictEqual(
+      new BN(balanceAfter.value.amount)
+        .sub(new BN(balanceBefore.value.amount))
+        .toString(),
+      amountOut.toString()
+    );
+    const poolAfter = await program.account.liquidityPool.fetch(poolPda);
+    assert.strictEqual(
+      poolAfter.reserveTwo.sub(pool.reserveTwo).toString(),
+      maxAmountIn.sub(getFee(maxAmountIn, new BN(config.buyFeeBps))).toString()
+    );
+  });
+
+  it("Remove liquidity", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        poolPda.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const poolTokenOne = await getAssociatedTokenAddress(mint1, poolPda, true);
+    const userAta1 = await getAssociatedTokenAddress(mint1, user.publicKey);
+    const shares = new BN(10);
+    const poolBefore = await program.account.liquidityPool.fetch(poolPda);
+    const providerBefore = await program.account.liquidityProvider.fetch(
+      liquidityProviderAccount
+    );
+
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
+      ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
+      await program.methods
+        .removeLiquidity(shares)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          mintTokenOne: mint1,
+          poolTokenAccountOne: poolTokenOne,
+          userTokenAccountOne: userAta1,
+          liquidityProviderAccount: liquidityProviderAccount,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    tx.feePayer = user.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    console.log(await connection.simulateTransaction(tx));
+    const sig = await sendAndConfirmTransaction(connection, tx, [user], {
+      skipPreflight: true,
+    });
+    console.log("Successfully Removed liquidity : ", sig);
+
+    // the provider gets its share of both reserves, rounded down
+    const poolAfter = await program.account.liquidityPool.fetch(poolPda);
+    const providerAfter = await program.account.liquidityProvider.fetch(
+      liquidityProviderAccount
+    );
+    assert.strictEqual(
+      providerBefore.shares.sub(providerAfter.shares).toString(),
+      shares.toString()
+    );
+    assert.strictEqual(
+      poolBefore.totalSupply.sub(poolAfter.totalSupply).toString(),
+      shares.toString()
+    );
+    assert.strictEqual(
+      poolBefore.reserveOne.sub(poolAfter.reserveOne).toString(),
+      shares.mul(poolBefore.reserveOne).div(poolBefore.totalSupply).toString()
+    );
+    assert.strictEqual(
+      poolBefore.reserveTwo.sub(poolAfter.reserveTwo).toString(),
+      shares.mul(poolBefore.reserveTwo).div(poolBefore.totalSupply).toString()
+    );
+  });
+
+  it("Pool SOL vault balance matches its reserve", async () => {
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const pool = await program.account.liquidityPool.fetch(poolPda);
+    const vaultBalance = await connection.getBalance(
+      getSolVault(poolPda, program.programId)
+    );
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+    assert.strictEqual(
+      (vaultBalance - rentExempt).toString(),
+      pool.reserveTwo.toString()
+    );
+  });
+
+  it("Launch a curve without SOL liquidity", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint2.toBuffer()],
+      program.programId
+    );
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        poolPda.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const solVault = getSolVault(poolPda, program.programId);
+    const poolTokenTwo = await getAssociatedTokenAddress(mint2, poolPda, true);
+    const userAta2 = await getAssociatedTokenAddress(mint2, user.publicKey);
+
+    await program.methods
+      .createPool(
+        { constantProduct: {} },
+        CONSTANT_PRODUCT_PARAMS,
+        { raydium: {} },
+        { instant: {} }
+      )
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: poolPda,
+        solVault,
+        mintTokenOne: mint2,
+        poolTokenAccountOne: poolTokenTwo,
+        payer: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    // tokens only, the opening price comes from the virtual SOL reserve
+    await program.methods
+      .addLiquidity(new BN(1000000000000000), new BN(0))
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: poolPda,
+        solVault,
+        mintTokenOne: mint2,
+        poolTokenAccountOne: poolTokenTwo,
+        userTokenAccountOne: userAta2,
+        liquidityProviderAccount,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const poolBefore = await program.account.liquidityPool.fetch(poolPda);
+    assert.strictEqual(poolBefore.reserveTwo.toString(), "0");
+
+    const amountIn = new BN(200000000);
+    const [tokenReserves, solReserves] = getEffectiveReserves(poolBefore);
+    const expectedOut = getAmountOut(
+      amountIn.sub(getFee(amountIn, new BN(config.buyFeeBps))),
+      solReserves,
+      tokenReserves
+    );
+
+    await program.methods
+      .swap(amountIn, { buy: {} }, expectedOut)
+      .accounts({
+        pool: poolPda,
+        solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: mint2,
+        poolTokenAccountOne: poolTokenTwo,
+        userTokenAccountOne: userAta2,
+        dexConfigurationAccount: curveConfig,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const poolAfter = await program.account.liquidityPool.fetch(poolPda);
+    assert.strictEqual(
+      poolAfter.reserveTwo.toString(),
+      amountIn.sub(getFee(amountIn, new BN(config.buyFeeBps))).toString()
+    );
+    assert.strictEqual(
+      poolBefore.reserveOne.sub(poolAfter.reserveOne).toString(),
+      expectedOut.toString()
+    );
+  });
+
+  it("Reject invalid curve parameters", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const mint = await createMint(
+      connection,
+      user,
+      user.publicKey,
+      user.publicKey,
+      tokenDecimal
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint.toBuffer()],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .createPool(
+          { exponential: {} },
+          [new BN(1000), new BN(0), new BN(0)],
+          { raydium: {} },
+          { instant: {} }
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          mintTokenOne: mint,
+          poolTokenAccountOne: await getAssociatedTokenAddress(
+            mint,
+            poolPda,
+            true
+          ),
+          payer: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("create_pool should reject a zero growth rate");
+    } catch (error) {
+      expect(String(error)).to.contain("InvalidCurveParameters");
+    }
+  });
+
+  it("Trade along a linear curve", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const mint = await createMint(
+      connection,
+      user,
+      user.publicKey,
+      user.publicKey,
+      tokenDecimal
+    );
+    const userAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user,
+        mint,
+        user.publicKey
+      )
+    ).address;
+    await mintTo(
+      connection,
+      user,
+      mint,
+      userAta,
+      user.publicKey,
+      BigInt(amount.toString())
+    );
+
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint.toBuffer()],
+      program.programId
+    );
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        poolPda.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const solVault = getSolVault(poolPda, program.programId);
+    const poolTokenAccount = await getAssociatedTokenAddress(
+      mint,
+      poolPda,
+      true
+    );
+    const basePrice = new BN(30_000);
+    const slope = new BN(1_000);
+
+    await program.methods
+      .createPool(
+        { linear: {} },
+        [basePrice, slope, new BN(0)],
+        { raydium: {} },
+        { instant: {} }
+      )
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: poolPda,
+        solVault,
+        mintTokenOne: mint,
+        poolTokenAccountOne: poolTokenAccount,
+        payer: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    await program.methods
+      .addLiquidity(new BN(1000000000000000), new BN(0))
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: poolPda,
+        solVault,
+        mintTokenOne: mint,
+        poolTokenAccountOne: poolTokenAccount,
+        userTokenAccountOne: userAta,
+        liquidityProviderAccount,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const accounts = {
+      pool: poolPda,
+      solVault,
+      treasury: treasury.publicKey,
+      mintTokenOne: mint,
+      poolTokenAccountOne: poolTokenAccount,
+      userTokenAccountOne: userAta,
+      dexConfigurationAccount: curveConfig,
+      user: user.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // buying the first tokens costs exactly the area under the curve plus the fee
+    const amountOut = new BN(10_000_000_000);
+    const cost = getLinearCost(basePrice, slope, amountOut);
+    const maxAmountIn = getAmountBeforeFee(cost, new BN(config.buyFeeBps));
+    const buyFee = getFee(maxAmountIn, new BN(config.buyFeeBps));
+    let treasuryBefore = await connection.getBalance(treasury.publicKey);
+    await program.methods
+      .swapExactOut(amountOut, { buy: {} }, maxAmountIn)
+      .accounts(accounts)
+      .signers([user])
+      .rpc();
+
+    let pool = await program.account.liquidityPool.fetch(poolPda);
+    assert.strictEqual(pool.tokensSold.toString(), amountOut.toString());
+    assert.strictEqual(
+      pool.reserveTwo.toString(),
+      maxAmountIn.sub(buyFee).toString()
+    );
+    assert.strictEqual(
+      (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+      buyFee.toNumber()
+    );
+
+    // selling half back pays out the area between the two supplies,
+    // less the fee
+    const amountIn = amountOut.divn(2);
+    const grossOut = cost.sub(
+      getLinearCost(basePrice, slope, amountOut.sub(amountIn))
+    );
+    const sellFee = getFee(grossOut, new BN(config.sellFeeBps));
+    const expectedOut = grossOut.sub(sellFee);
+    treasuryBefore = await connection.getBalance(treasury.publicKey);
+    await program.methods
+      .swap(amountIn, { sell: {} }, expectedOut)
+      .accounts(accounts)
+      .signers([user])
+      .rpc();
+
+    pool = await program.account.liquidityPool.fetch(poolPda);
+    assert.strictEqual(
+      pool.reserveTwo.toString(),
+      maxAmountIn.sub(buyFee).sub(grossOut).toString()
+    );
+    assert.strictEqual(
+      (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+      sellFee.toNumber()
+    );
+  });
+
+  it("Trade along an exponential curve", async () => {
+    await tradeAlongCurve(program, user, { exponential: {} }, [
+      new BN(1_000),
+      new BN(1_000_000_000),
+      new BN(0),
+    ]);
+  });
+
+  it("Trade along a sigmoid curve", async () => {
+    await tradeAlongCurve(program, user, { sigmoid: {} }, [
+      new BN(100_000),
+      new BN(1_000_000_000),
+      new BN(500_000_000_000),
+    ]);
+  });
+
+  it("Complete the curve and reject further trades", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000)
+    );
+    const accounts = {
+      pool: launch.pool,
+      solVault: launch.solVault,
+      treasury: treasury.publicKey,
+      mintTokenOne: launch.mint,
+      poolTokenAccountOne: launch.poolTokenAccount,
+      userTokenAccountOne: launch.userTokenAccount,
+      dexConfigurationAccount: curveConfig,
+      user: user.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // an exact-out buy past the threshold fails instead of being trimmed
+    try {
+      await program.methods
+        .swapExactOut(
+          COMPLETION_TOKENS_SOLD.addn(1),
+          { buy: {} },
+          new BN(30_000_000_000)
+        )
+        .accounts(accounts)
+        .signers([user])
+        .rpc();
+      assert.fail("an exact output past the threshold should be rejected");
+    } catch (error) {
+      expect(String(error)).to.contain("ExactOutputUnavailable");
+    }
+
+    // the final buy only takes the supply left on the curve and pays for that much
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const poolBefore = await program.account.liquidityPool.fetch(launch.pool);
+    const [tokenReserves, solReserves] = getEffectiveReserves(poolBefore);
+    const expectedIn = getAmountBeforeFee(
+      getAmountIn(COMPLETION_TOKENS_SOLD, solReserves, tokenReserves),
+      new BN(config.buyFeeBps)
+    );
+    await program.methods
+      .swap(new BN(30_000_000_000), { buy: {} }, new BN(0))
+      .accounts(accounts)
+      .signers([user])
+      .rpc();
+
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    completedLaunch = launch;
+    assert.deepEqual(pool.status, { completed: {} });
+    assert.strictEqual(
+      pool.tokensSold.toString(),
+      COMPLETION_TOKENS_SOLD.toString()
+    );
+    assert.strictEqual(
+      pool.reserveTwo.toString(),
+      expectedIn.sub(getFee(expectedIn, new BN(config.buyFeeBps))).toString()
+    );
+
+    try {
+      await program.methods
+        .swap(new BN(200000000), { buy: {} }, new BN(0))
+        .accounts(accounts)
+        .signers([user])
+        .rpc();
+      assert.fail("a completed pool should reject swaps");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotActive");
+    }
+  });
+
+  it("Reject migrating an active pool", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const [poolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint1.toBuffer()],
+      program.programId
+    );
+    const payerWsolAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user,
+        NATIVE_MINT,
+        user.publicKey
+      )
+    ).address;
+
+    try {
+      await program.methods
+        .migrateToRaydium()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: poolPda,
+          solVault: getSolVault(poolPda, program.programId),
+          mintTokenOne: mint1,
+          poolTokenAccountOne: await getAssociatedTokenAddress(
+            mint1,
+            poolPda,
+            true
+          ),
+          wsolMint: NATIVE_MINT,
+          payerTokenAccount: tokenAta1,
+          payerWsolAccount,
+          ...getRaydiumAccounts(mint1, user.publicKey),
+          payer: user.publicKey,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("migrate_to_raydium should require a completed curve");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotCompleted");
+    }
+  });
+
+  it("Migrate a completed pool to Raydium", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const launch = completedLaunch;
+    const poolBefore = await program.account.liquidityPool.fetch(launch.pool);
+    const raydiumAccounts = getRaydiumAccounts(launch.mint, user.publicKey);
+    const payerWsolAccount = await getAssociatedTokenAddress(
+      NATIVE_MINT,
+      user.publicKey
+    );
+    const migrate = (accounts: ReturnType<typeof getRaydiumAccounts>) =>
+      program.methods
+        .migrateToRaydium()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          wsolMint: NATIVE_MINT,
+          payerTokenAccount: launch.userTokenAccount,
+          payerWsolAccount,
+          ...accounts,
+          payer: user.publicKey,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+        ])
+        .signers([user])
+        .rpc();
+
+    // the caller cannot pick another fee tier than the configured one
+    try {
+      await migrate(
+        getRaydiumAccounts(launch.mint, user.publicKey, getRaydiumAmmConfig(1))
+      );
+      assert.fail("migrate_to_raydium should only take the configured tier");
+    } catch (error) {
+      expect(String(error)).to.contain("ConstraintAddress");
+    }
+
+    await migrate(raydiumAccounts);
+
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { migrated: {} });
+    assert.strictEqual(pool.reserveOne.toString(), "0");
+    assert.strictEqual(pool.reserveTwo.toString(), "0");
+
+    // both reserves now sit in the AMM vaults
+    const tokenFirst =
+      Buffer.compare(launch.mint.toBuffer(), NATIVE_MINT.toBuffer()) < 0;
+    const [tokenVault, wsolVault] = tokenFirst
+      ? [raydiumAccounts.token0Vault, raydiumAccounts.token1Vault]
+      : [raydiumAccounts.token1Vault, raydiumAccounts.token0Vault];
+    assert.strictEqual(
+      (await getAccount(connection, tokenVault)).amount.toString(),
+      poolBefore.reserveOne.toString()
+    );
+    assert.strictEqual(
+      (await getAccount(connection, wsolVault)).amount.toString(),
+      poolBefore.reserveTwo.toString()
+    );
+
+    // the creator's LP tokens were burned
+    const lpToken = await getAccount(connection, raydiumAccounts.payerLpToken);
+    assert.strictEqual(lpToken.amount.toString(), "0");
+    const lpMint = await getMint(connection, raydiumAccounts.lpMint);
+    assert.strictEqual(lpMint.supply.toString(), "0");
+
+    try {
+      await program.methods
+        .swap(new BN(200000000), { buy: {} }, new BN(0))
+        .accounts({
+          pool: launch.pool,
+          solVault: launch.solVault,
+          treasury: treasury.publicKey,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: launch.userTokenAccount,
+          dexConfigurationAccount: PublicKey.findProgramAddressSync(
+            [Buffer.from(curveSeed)],
+            program.programId
+          )[0],
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("a migrated pool should reject swaps");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotActive");
+    }
+  });
+
+  it("Migrate a completed pool to Meteora", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { meteora: {} }
+    );
+    await program.methods
+      .swap(new BN(30_000_000_000), { buy: {} }, new BN(0))
+      .accounts({
+        pool: launch.pool,
+        solVault: launch.solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: launch.mint,
+        poolTokenAccountOne: launch.poolTokenAccount,
+        userTokenAccountOne: launch.userTokenAccount,
+        dexConfigurationAccount: curveConfig,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const poolBefore = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(poolBefore.status, { completed: {} });
+    assert.deepEqual(poolBefore.migrationTarget, { meteora: {} });
+
+    const payerWsolAccount = await getAssociatedTokenAddress(
+      NATIVE_MINT,
+      user.publicKey
+    );
+    const migrationAccounts = {
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      mintTokenOne: launch.mint,
+      poolTokenAccountOne: launch.poolTokenAccount,
+      wsolMint: NATIVE_MINT,
+      payerTokenAccount: launch.userTokenAccount,
+      payerWsolAccount,
+      payer: user.publicKey,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+    };
+
+    // the pool was launched for Meteora, so Raydium cannot take its liquidity
+    try {
+      await program.methods
+        .migrateToRaydium()
+        .accounts({
+          ...migrationAccounts,
+          ...getRaydiumAccounts(launch.mint, user.publicKey),
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("migrate_to_raydium should reject a Meteora launch");
+    } catch (error) {
+      expect(String(error)).to.contain("InvalidMigrationTarget");
+    }
+
+    const meteoraAccounts = getMeteoraAccounts(launch.mint, user.publicKey);
+
+    // nor can the caller point the CPI at a pool of its choosing
+    try {
+      await program.methods
+        .migrateToMeteora()
+        .accounts({
+          ...migrationAccounts,
+          ...meteoraAccounts,
+          meteoraPool: Keypair.generate().publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("migrate_to_meteora should only take the derived pool");
+    } catch (error) {
+      expect(String(error)).to.contain("ConstraintAddress");
+    }
+
+    await program.methods
+      .migrateToMeteora()
+      .accounts({ ...migrationAccounts, ...meteoraAccounts })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+      ])
+      .signers([user])
+      .rpc();
+
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { migrated: {} });
+    assert.strictEqual(pool.reserveOne.toString(), "0");
+    assert.strictEqual(pool.reserveTwo.toString(), "0");
+
+    // both reserves now sit in the AMM vaults
+    assert.strictEqual(
+      (await getAccount(connection, meteoraAccounts.aVault)).amount.toString(),
+      poolBefore.reserveOne.toString()
+    );
+    assert.strictEqual(
+      (await getAccount(connection, meteoraAccounts.bVault)).amount.toString(),
+      poolBefore.reserveTwo.toString()
+    );
+
+    // the creator's LP tokens were burned
+    const lpToken = await getAccount(connection, meteoraAccounts.payerLpToken);
+    assert.strictEqual(lpToken.amount.toString(), "0");
+    const lpMint = await getMint(connection, meteoraAccounts.lpMint);
+    assert.strictEqual(lpMint.supply.toString(), "0");
+  });
+
+  it("Reopen a completed pool whose migration cannot go through", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000)
+    );
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        launch.pool.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const accounts = {
+      pool: launch.pool,
+      solVault: launch.solVault,
+      treasury: treasury.publicKey,
+      mintTokenOne: launch.mint,
+      poolTokenAccountOne: launch.poolTokenAccount,
+      userTokenAccountOne: launch.userTokenAccount,
+      dexConfigurationAccount: curveConfig,
+      user: user.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+    const reopenPool = (admin: Keypair) =>
+      program.methods
+        .reopenPool()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          mintTokenOne: launch.mint,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    try {
+      await reopenPool(user);
+      assert.fail("only a completed pool should reopen");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotCompleted");
+    }
+
+    await program.methods
+      .swap(new BN(30_000_000_000), { buy: {} }, new BN(0))
+      .accounts(accounts)
+      .signers([user])
+      .rpc();
+    let pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { completed: {} });
+
+    try {
+      await reopenPool(user2);
+      assert.fail("only the admin should reopen a pool");
+    } catch (error) {
+      expect(String(error)).to.contain("ConstraintHasOne");
+    }
+
+    await reopenPool(user);
+    pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { active: {} });
+
+    // holders sell back on the curve and the provider withdraws again
+    await program.methods
+      .swap(pool.tokensSold.divn(2), { sell: {} }, new BN(0))
+      .accounts(accounts)
+      .signers([user])
+      .rpc();
+    await program.methods
+      .removeLiquidity(new BN(10))
+      .accounts({ ...accounts, liquidityProviderAccount })
+      .signers([user])
+      .rpc();
+
+    const reopened = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(reopened.status, { active: {} });
+    assert.isTrue(reopened.tokensSold.lt(pool.tokensSold));
+    assert.isTrue(reopened.totalSupply.eq(pool.totalSupply.subn(10)));
+  });
+
+  it("Launch a token in one instruction", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const mint = Keypair.generate();
+    const [pool] = PublicKey.findProgramAddressSync(
+      [Buffer.from(POOL_SEED_PREFIX), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [metadata] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("metadata"),
+        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+        mint.publicKey.toBuffer(),
+      ],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+    const solVault = getSolVault(pool, program.programId);
+    const poolTokenAccount = await getAssociatedTokenAddress(
+      mint.publicKey,
+      pool,
+      true
+    );
+
+    await program.methods
+      .createToken(
+        "Pump Token",
+        "PUMP",
+        "https://example.com/pump.json",
+        { constantProduct: {} },
+        CONSTANT_PRODUCT_PARAMS,
+        { raydium: {} },
+        { instant: {} }
+      )
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool,
+        solVault,
+        mint: mint.publicKey,
+        poolTokenAccountOne: poolTokenAccount,
+        metadata,
+        payer: user.publicKey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+      ])
+      .signers([user, mint])
+      .rpc();
+
+    // the whole supply sits in the pool and can never grow or be frozen
+    const mintAccount = await getMint(connection, mint.publicKey);
+    assert.strictEqual(
+      mintAccount.supply.toString(),
+      TOKEN_TOTAL_SUPPLY.toString()
+    );
+    assert.strictEqual(mintAccount.decimals, 6);
+    assert.isNull(mintAccount.mintAuthority);
+    assert.isNull(mintAccount.freezeAuthority);
+    assert.strictEqual(
+      (await getAccount(connection, poolTokenAccount)).amount.toString(),
+      TOKEN_TOTAL_SUPPLY.toString()
+    );
+
+    const poolAccount = await program.account.liquidityPool.fetch(pool);
+    assert.strictEqual(
+      poolAccount.reserveOne.toString(),
+      TOKEN_TOTAL_SUPPLY.toString()
+    );
+    assert.strictEqual(poolAccount.reserveTwo.toString(), "0");
+    assert.deepEqual(poolAccount.status, { active: {} });
+
+    const metadataAccount = await connection.getAccountInfo(metadata);
+    assert.isTrue(metadataAccount.owner.equals(TOKEN_METADATA_PROGRAM_ID));
+    assert.include(metadataAccount.data.toString(), "Pump Token");
+
+    // the launch trades right away
+    const userTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user,
+        mint.publicKey,
+        user.publicKey
+      )
+    ).address;
+    await program.methods
+      .swap(new BN(200000000), { buy: {} }, new BN(1))
+      .accounts({
+        pool,
+        solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: mint.publicKey,
+        poolTokenAccountOne: poolTokenAccount,
+        userTokenAccountOne: userTokenAccount,
+        dexConfigurationAccount: curveConfig,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    assert.notStrictEqual(
+      (await getAccount(connection, userTokenAccount)).amount.toString(),
+      "0"
+    );
+  });
+
+  it("Settle an oversubscribed presale pro rata", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const endSlot = (await connection.getSlot()) + 40;
+    const softCap = new BN(1_000_000_000);
+    const hardCap = new BN(3_000_000_000);
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { raydium: {} },
+      {
+        presale: {
+          startSlot: new BN(0),
+          endSlot: new BN(endSlot),
+          softCap,
+          hardCap,
+          allowlistRoot: null,
+        },
+      }
+    );
+    const deposit = (bidder: Keypair, amount: BN) =>
+      program.methods
+        .presaleDeposit(amount, new BN(0), [])
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          presaleBid: getPresaleBid(
+            launch.pool,
+            bidder.publicKey,
+            program.programId
+          ),
+          mintTokenOne: launch.mint,
+          user: bidder.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bidder])
+        .rpc();
+    const launchAccounts = {
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      treasury: treasury.publicKey,
+      mintTokenOne: launch.mint,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // public trading waits for the launch
+    try {
+      await program.methods
+        .swap(new BN(200000000), { buy: {} }, new BN(0))
+        .accounts({
+          pool: launch.pool,
+          solVault: launch.solVault,
+          treasury: treasury.publicKey,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: launch.userTokenAccount,
+          dexConfigurationAccount: curveConfig,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("a presale pool should reject swaps");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotActive");
+    }
+
+    // bids keep coming past the hard cap
+    const bids: [Keypair, BN][] = [
+      [user, new BN(1_000_000_001)],
+      [user2, new BN(3_000_000_000)],
+    ];
+    for (const [bidder, bid] of bids) {
+      await deposit(bidder, bid);
+    }
+    const totalDeposits = new BN(4_000_000_001);
+    const oversubscribed = totalDeposits.sub(hardCap);
+
+    try {
+      await program.methods.launch().accounts(launchAccounts).rpc();
+      assert.fail("launch should wait for the window to end");
+    } catch (error) {
+      expect(String(error)).to.contain("PresaleNotEnded");
+    }
+
+    // only the hard cap is spent on the opening buy, charged the buy fee
+    await waitForSlot(endSlot + 1);
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const poolBefore = await program.account.liquidityPool.fetch(launch.pool);
+    const [tokenReserves, solReserves] = getEffectiveReserves(poolBefore);
+    const fee = getFee(hardCap, new BN(config.buyFeeBps));
+    const expectedTokens = getAmountOut(
+      hardCap.sub(fee),
+      solReserves,
+      tokenReserves
+    );
+    const treasuryBefore = await connection.getBalance(treasury.publicKey);
+    await program.methods.launch().accounts(launchAccounts).rpc();
+
+    // the fee leaves the vault for the treasury
+    const launched = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(launched.status, { active: {} });
+    assert.strictEqual(
+      launched.reserveTwo.toString(),
+      hardCap.sub(fee).toString()
+    );
+    assert.strictEqual(
+      (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+      fee.toNumber()
+    );
+    assert.strictEqual(
+      launched.presale.acceptedDeposits.toString(),
+      hardCap.toString()
+    );
+    assert.strictEqual(
+      launched.presale.tokensAllocated.toString(),
+      expectedTokens.toString()
+    );
+    assert.strictEqual(
+      poolBefore.reserveOne.sub(launched.reserveOne).toString(),
+      expectedTokens.toString()
+    );
+
+    // bidders share the tokens and the unspent SOL in proportion to their deposits
+    let tokensClaimed = new BN(0);
+    let refundsPaid = new BN(0);
+    for (const [bidder, bid] of bids) {
+      const bidderTokenAccount = (
+        await getOrCreateAssociatedTokenAccount(
+          connection,
+          bidder,
+          launch.mint,
+          bidder.publicKey
+        )
+      ).address;
+      const balanceBefore = (await getAccount(connection, bidderTokenAccount))
+        .amount;
+      const vaultBefore = await connection.getBalance(launch.solVault);
+      const presaleBid = getPresaleBid(
+        launch.pool,
+        bidder.publicKey,
+        program.programId
+      );
+
+      await program.methods
+        .presaleClaim()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          presaleBid,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: bidderTokenAccount,
+          user: bidder.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bidder])
+        .rpc();
+
+      const tokens = bid.mul(expectedTokens).div(totalDeposits);
+      const refund = bid.mul(oversubscribed).div(totalDeposits);
+      const balanceAfter = (await getAccount(connection, bidderTokenAccount))
+        .amount;
+      assert.strictEqual(
+        (balanceAfter - balanceBefore).toString(),
+        tokens.toString()
+      );
+      assert.strictEqual(
+        vaultBefore - (await connection.getBalance(launch.solVault)),
+        refund.toNumber()
+      );
+      assert.isNull(await connection.getAccountInfo(presaleBid));
+      tokensClaimed = tokensClaimed.add(tokens);
+      refundsPaid = refundsPaid.add(refund);
+    }
+
+    // the last claim routes the rounding dust into the reserves
+    const settled = await program.account.liquidityPool.fetch(launch.pool);
+    assert.strictEqual(
+      settled.reserveOne.sub(launched.reserveOne).toString(),
+      expectedTokens.sub(tokensClaimed).toString()
+    );
+    assert.strictEqual(
+      settled.reserveTwo.toString(),
+      hardCap.sub(fee).add(oversubscribed).sub(refundsPaid).toString()
+    );
+  });
+
+  it("Refund a presale that missed its soft cap", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const endSlot = (await connection.getSlot()) + 40;
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { raydium: {} },
+      {
+        presale: {
+          startSlot: new BN(0),
+          endSlot: new BN(endSlot),
+          softCap: new BN(5_000_000_000),
+          hardCap: new BN(10_000_000_000),
+          allowlistRoot: null,
+        },
+      }
+    );
+    const bid = new BN(1_000_000_000);
+    const presaleBid = getPresaleBid(
+      launch.pool,
+      user2.publicKey,
+      program.programId
+    );
+    await program.methods
+      .presaleDeposit(bid, new BN(0), [])
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        presaleBid,
+        mintTokenOne: launch.mint,
+        user: user2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user2])
+      .rpc();
+
+    const refundAccounts = {
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      presaleBid,
+      mintTokenOne: launch.mint,
+      user: user2.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+    try {
+      await program.methods
+        .presaleRefund()
+        .accounts(refundAccounts)
+        .signers([user2])
+        .rpc();
+      assert.fail("bids stay locked while the presale is open");
+    } catch (error) {
+      expect(String(error)).to.contain("PresaleNotRefundable");
+    }
+
+    // launching below the soft cap cancels the presale instead
+    await waitForSlot(endSlot + 1);
+    await program.methods
+      .launch()
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: launch.mint,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    const cancelled = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(cancelled.status, { cancelled: {} });
+
+    // the bid and its account rent both go back to the bidder
+    const bidRent = await connection.getBalance(presaleBid);
+    const balanceBefore = await connection.getBalance(user2.publicKey);
+    await program.methods
+      .presaleRefund()
+      .accounts(refundAccounts)
+      .signers([user2])
+      .rpc();
+    assert.strictEqual(
+      (await connection.getBalance(user2.publicKey)) - balanceBefore,
+      bid.toNumber() + bidRent
+    );
+    assert.isNull(await connection.getAccountInfo(presaleBid));
+
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { cancelled: {} });
+    assert.strictEqual(pool.presale.totalDeposits.toString(), "0");
+
+    try {
+      await program.methods
+        .swap(new BN(200000000), { buy: {} }, new BN(0))
+        .accounts({
+          pool: launch.pool,
+          solVault: launch.solVault,
+          treasury: treasury.publicKey,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: launch.userTokenAccount,
+          dexConfigurationAccount: curveConfig,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("a cancelled pool should reject swaps");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotActive");
+    }
+
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        launch.pool.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    try {
+      await program.methods
+        .addLiquidity(new BN(1000000), new BN(0))
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: launch.userTokenAccount,
+          liquidityProviderAccount,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("a cancelled pool should reject new liquidity");
+    } catch (error) {
+      expect(String(error)).to.contain("PoolNotActive");
+    }
+  });
+
+  it("Cancel a presale nobody bid on and release its liquidity", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const endSlot = (await connection.getSlot()) + 10;
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { raydium: {} },
+      {
+        presale: {
+          startSlot: new BN(0),
+          endSlot: new BN(endSlot),
+          softCap: new BN(1_000_000_000),
+          hardCap: new BN(10_000_000_000),
+          allowlistRoot: null,
+        },
+      }
+    );
+
+    await waitForSlot(endSlot + 1);
+    await program.methods
+      .launch()
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: launch.mint,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { cancelled: {} });
+
+    // the provider takes the launch supply back
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        launch.pool.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const { shares } = await program.account.liquidityProvider.fetch(
+      liquidityProviderAccount
+    );
+    const balanceBefore = (
+      await getAccount(connection, launch.userTokenAccount)
+    ).amount;
+    await program.methods
+      .removeLiquidity(shares)
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        mintTokenOne: launch.mint,
+        poolTokenAccountOne: launch.poolTokenAccount,
+        userTokenAccountOne: launch.userTokenAccount,
+        liquidityProviderAccount,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+    const balanceAfter = (await getAccount(connection, launch.userTokenAccount))
+      .amount;
+    assert.isTrue(balanceAfter > balanceBefore);
+  });
+
+  it("Cap allowlisted presale bids with merkle proofs", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const slot = await connection.getSlot();
+    const allocations: [Keypair, BN][] = [
+      [user, new BN(2_000_000_000)],
+      [user2, new BN(1_000_000_000)],
+    ];
+    const leaves = allocations.map(([wallet, cap]) =>
+      getAllowlistLeaf(wallet.publicKey, cap)
+    );
+    const tree = buildMerkleTree(leaves);
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { raydium: {} },
+      {
+        presale: {
+          startSlot: new BN(slot),
+          endSlot: new BN(slot + 10_000),
+          softCap: new BN(1_000_000_000),
+          hardCap: new BN(10_000_000_000),
+          allowlistRoot: Array.from(getMerkleRoot(tree)),
+        },
+      }
+    );
+    const deposit = (
+      bidder: Keypair,
+      amount: BN,
+      maxAllocation: BN,
+      proof: Buffer[]
+    ) =>
+      program.methods
+        .presaleDeposit(
+          amount,
+          maxAllocation,
+          proof.map((node) => Array.from(node))
+        )
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          presaleBid: getPresaleBid(
+            launch.pool,
+            bidder.publicKey,
+            program.programId
+          ),
+          mintTokenOne: launch.mint,
+          user: bidder.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bidder])
+        .rpc();
+
+    // bids add up against the cap committed in the leaf
+    const userProof = getMerkleProof(tree, 0);
+    await deposit(user, new BN(1_500_000_000), allocations[0][1], userProof);
+    await deposit(user, new BN(500_000_000), allocations[0][1], userProof);
+    try {
+      await deposit(user, new BN(1), allocations[0][1], userProof);
+      assert.fail("bids should stop at the wallet's allocation");
+    } catch (error) {
+      expect(String(error)).to.contain("PresaleAllocationExceeded");
+    }
+
+    // claiming a bigger cap than the leaf commits to breaks the proof
+    const user2Proof = getMerkleProof(tree, 1);
+    try {
+      await deposit(
+        user2,
+        new BN(1_500_000_000),
+        allocations[0][1],
+        user2Proof
+      );
+      assert.fail("a forged allocation should be rejected");
+    } catch (error) {
+      expect(String(error)).to.contain("NotAllowlisted");
+    }
+
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey, 2_000_000_000);
+    try {
+      await deposit(
+        outsider,
+        new BN(100_000_000),
+        allocations[1][1],
+        user2Proof
+      );
+      assert.fail("wallets off the allowlist should be rejected");
+    } catch (error) {
+      expect(String(error)).to.contain("NotAllowlisted");
+    }
+
+    await deposit(user2, new BN(1_000_000_000), allocations[1][1], user2Proof);
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.strictEqual(pool.presale.totalDeposits.toString(), "3000000000");
+  });
+  it("Sell a tranche by Dutch auction before the curve opens", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const startSlot = await connection.getSlot();
+    const endSlot = startSlot + 40;
+    const startPrice = new BN(2_000_000);
+    const floorPrice = new BN(1_000_000);
+    const tranche = new BN(1_000_000_000_000);
+    const tokenAmount = new BN(1000000000000000);
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      tokenAmount,
+      { raydium: {} },
+      {
+        dutchAuction: {
+          startSlot: new BN(startSlot),
+          endSlot: new BN(endSlot),
+          startPrice,
+          floorPrice,
+          tranche,
+        },
+      }
+    );
+    const buyerTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user2,
+        launch.mint,
+        user2.publicKey
+      )
+    ).address;
+    const auctionBuy = (amount: BN, maxSolIn: BN) =>
+      program.methods
+        .auctionBuy(amount, maxSolIn)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: buyerTokenAccount,
+          user: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+    const launchAccounts = {
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      treasury: treasury.publicKey,
+      mintTokenOne: launch.mint,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // buyers pay the decayed price, never less than the floor
+    const amount = tranche.divn(2);
+    const maxSolIn = new BN(1_000_000_000);
+    try {
+      await auctionBuy(amount, new BN(1));
+      assert.fail("the price should exceed the buyer's limit");
+    } catch (error) {
+      expect(String(error)).to.contain("SlippageExceeded");
+    }
+
+    const vaultBefore = await connection.getBalance(launch.solVault);
+    await auctionBuy(amount, maxSolIn);
+    const cost = new BN(
+      (await connection.getBalance(launch.solVault)) - vaultBefore
+    );
+    assert.strictEqual(
+      (await getAccount(connection, buyerTokenAccount)).amount.toString(),
+      amount.toString()
+    );
+    assert.isTrue(cost.gte(amount.mul(floorPrice).div(PRICE_SCALE)));
+    assert.isTrue(cost.lte(amount.mul(startPrice).div(PRICE_SCALE)));
+
+    const auctioning = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(auctioning.status, { auction: {} });
+    assert.strictEqual(
+      auctioning.auction.tokensSold.toString(),
+      amount.toString()
+    );
+    assert.strictEqual(
+      auctioning.auction.solRaised.toString(),
+      cost.toString()
+    );
+
+    try {
+      await program.methods.launch().accounts(launchAccounts).rpc();
+      assert.fail("the auction should run until its end slot");
+    } catch (error) {
+      expect(String(error)).to.contain("AuctionNotEnded");
+    }
+
+    await waitForSlot(endSlot + 1);
+    try {
+      await auctionBuy(amount, maxSolIn);
+      assert.fail("the auction should stop selling after its end slot");
+    } catch (error) {
+      expect(String(error)).to.contain("AuctionNotOpen");
+    }
+
+    // the unsold tokens and the raised SOL seed the curve, the tranche sold so
+    // far is part of its supply
+    await program.methods.launch().accounts(launchAccounts).rpc();
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.status, { active: {} });
+    assert.strictEqual(
+      pool.reserveOne.toString(),
+      tokenAmount.sub(amount).toString()
+    );
+    assert.strictEqual(pool.reserveTwo.toString(), cost.toString());
+    assert.strictEqual(pool.tokensSold.toString(), amount.toString());
+  });
+
+  it("Sell auction tokens back on a supply curve", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const basePrice = new BN(30_000);
+    const slope = new BN(1_000);
+    const startSlot = await connection.getSlot();
+    const endSlot = startSlot + 40;
+    const tranche = new BN(1_000_000_000_000);
+    const auctionLaunch = (floorPrice: BN) =>
+      launchPool(
+        program,
+        user,
+        { linear: {} },
+        [basePrice, slope, new BN(0)],
+        new BN(1000000000000000),
+        { raydium: {} },
+        {
+          dutchAuction: {
+            startSlot: new BN(startSlot),
+            endSlot: new BN(endSlot),
+            startPrice: new BN(2_000_000),
+            floorPrice,
+            tranche,
+          },
+        }
+      );
+
+    // the floor has to cover what the curve charges for the whole tranche
+    try {
+      await auctionLaunch(new BN(100_000));
+      assert.fail("a floor below the curve's price should be rejected");
+    } catch (error) {
+      expect(String(error)).to.contain("InvalidAuctionParameters");
+    }
+
+    const launch = await auctionLaunch(new BN(1_000_000));
+    const buyerTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user2,
+        launch.mint,
+        user2.publicKey
+      )
+    ).address;
+    const amount = tranche.divn(2);
+    await program.methods
+      .auctionBuy(amount, new BN(1_000_000_000))
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        mintTokenOne: launch.mint,
+        poolTokenAccountOne: launch.poolTokenAccount,
+        userTokenAccountOne: buyerTokenAccount,
+        user: user2.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    await waitForSlot(endSlot + 1);
+    await program.methods
+      .launch()
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: launch.mint,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    const opened = await program.account.liquidityPool.fetch(launch.pool);
+    assert.strictEqual(opened.tokensSold.toString(), amount.toString());
+
+    // the buyer sells the whole tranche share back along the curve
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const grossOut = getLinearCost(basePrice, slope, amount);
+    const expectedOut = grossOut.sub(
+      getFee(grossOut, new BN(config.sellFeeBps))
+    );
+    await program.methods
+      .swap(amount, { sell: {} }, expectedOut)
+      .accounts({
+        pool: launch.pool,
+        solVault: launch.solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: launch.mint,
+        poolTokenAccountOne: launch.poolTokenAccount,
+        userTokenAccountOne: buyerTokenAccount,
+        dexConfigurationAccount: curveConfig,
+        user: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user2])
+      .rpc();
+
+    // the auction SOL paid for it, what the floor raised above the curve stays
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.strictEqual(pool.tokensSold.toString(), "0");
+    assert.strictEqual(
+      pool.reserveTwo.toString(),
+      opened.reserveTwo.sub(grossOut).toString()
+    );
+    assert.isTrue(pool.reserveTwo.gtn(0));
+  });
+  it("Clear opening orders at one uniform price", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { raydium: {} },
+      { batchAuction: { slots: new BN(40) } }
+    );
+    const placeOrder = (buyer: Keypair, lamports: BN) =>
+      program.methods
+        .placeOrder(lamports)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          batchOrder: getBatchOrder(
+            launch.pool,
+            buyer.publicKey,
+            program.programId
+          ),
+          mintTokenOne: launch.mint,
+          user: buyer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+    const claimOrder = (buyer: Keypair) =>
+      claimBatchOrder(program, launch, buyer);
+    const launchAccounts = {
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      treasury: treasury.publicKey,
+      mintTokenOne: launch.mint,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // repeat orders from one wallet add up in its order account
+    await placeOrder(user, new BN(500_000_000));
+    await placeOrder(user2, new BN(2_000_000_000));
+    await placeOrder(user, new BN(500_000_001));
+    const orders: [Keypair, BN][] = [
+      [user, new BN(1_000_000_001)],
+      [user2, new BN(2_000_000_000)],
+    ];
+    const totalOrders = new BN(3_000_000_001);
+
+    const order = await program.account.batchOrder.fetch(
+      getBatchOrder(launch.pool, user.publicKey, program.programId)
+    );
+    assert.strictEqual(order.lamports.toString(), "1000000001");
+    try {
+      await claimOrder(user);
+      assert.fail("orders should wait for the clearing");
+    } catch (error) {
+      expect(String(error)).to.contain("BatchAuctionNotEnded");
+    }
+    try {
+      await program.methods.launch().accounts(launchAccounts).rpc();
+      assert.fail("the batch should clear only once its window closed");
+    } catch (error) {
+      expect(String(error)).to.contain("BatchAuctionNotEnded");
+    }
+
+    const poolBefore = await program.account.liquidityPool.fetch(launch.pool);
+    await waitForSlot(poolBefore.batchAuction.endSlot.toNumber() + 1);
+    try {
+      await placeOrder(user2, new BN(1_000_000_000));
+      assert.fail("orders should stop once the window closed");
+    } catch (error) {
+      expect(String(error)).to.contain("BatchAuctionNotOpen");
+    }
+
+    // every order fills as one buy on the curve, fee included
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const fee = getFee(totalOrders, new BN(config.buyFeeBps));
+    const [tokenReserves, solReserves] = getEffectiveReserves(poolBefore);
+    const expectedTokens = getAmountOut(
+      totalOrders.sub(fee),
+      solReserves,
+      tokenReserves
+    );
+    const treasuryBefore = await connection.getBalance(treasury.publicKey);
+    await program.methods.launch().accounts(launchAccounts).rpc();
+
+    const cleared = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(cleared.status, { active: {} });
+    assert.strictEqual(
+      cleared.batchAuction.tokensCleared.toString(),
+      expectedTokens.toString()
+    );
+    assert.strictEqual(
+      cleared.reserveTwo.sub(poolBefore.reserveTwo).toString(),
+      totalOrders.sub(fee).toString()
+    );
+    assert.strictEqual(
+      (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+      fee.toNumber()
+    );
+
+    // each wallet gets its share of the tokens, so all pay the same price
+    let tokensClaimed = new BN(0);
+    for (const [buyer, lamports] of orders) {
+      const tokens = lamports.mul(expectedTokens).div(totalOrders);
+      const claimed = await claimOrder(buyer);
+      assert.strictEqual(claimed.tokens.toString(), tokens.toString());
+      assert.strictEqual(claimed.refund, 0);
+      tokensClaimed = tokensClaimed.add(tokens);
+    }
+    try {
+      await claimOrder(user);
+      assert.fail("an order should only be claimed once");
+    } catch (error) {
+      expect(String(error)).to.contain("AccountNotInitialized");
+    }
+
+    // the last claim routes the rounding dust into the reserves
+    const settled = await program.account.liquidityPool.fetch(launch.pool);
+    assert.strictEqual(
+      settled.reserveOne.sub(cleared.reserveOne).toString(),
+      expectedTokens.sub(tokensClaimed).toString()
+    );
+  });
+  it("Refund the part of a batch the pool cannot fill", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    // far fewer real tokens than the virtual reserves would quote
+    const tokenAmount = new BN(1_000_000);
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      tokenAmount,
+      { raydium: {} },
+      { batchAuction: { slots: new BN(10) } }
+    );
+    const lamports = new BN(2_000_000_000);
+    await program.methods
+      .placeOrder(lamports)
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        batchOrder: getBatchOrder(
+          launch.pool,
+          user2.publicKey,
+          program.programId
+        ),
+        mintTokenOne: launch.mint,
+        user: user2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user2])
+      .rpc();
+
+    // the fill stops at the real reserves and pays only for those tokens
+    const poolBefore = await program.account.liquidityPool.fetch(launch.pool);
+    await waitForSlot(poolBefore.batchAuction.endSlot.toNumber() + 1);
+    const config = await program.account.curveConfiguration.fetch(curveConfig);
+    const [tokenReserves, solReserves] = getEffectiveReserves(poolBefore);
+    const accepted = getAmountBeforeFee(
+      getAmountIn(tokenAmount, solReserves, tokenReserves),
+      new BN(config.buyFeeBps)
+    );
+    await program.methods
+      .launch()
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pool: launch.pool,
+        solVault: launch.solVault,
+        treasury: treasury.publicKey,
+        mintTokenOne: launch.mint,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const cleared = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(cleared.status, { active: {} });
+    assert.strictEqual(cleared.reserveOne.toString(), "0");
+    assert.strictEqual(
+      cleared.batchAuction.acceptedOrders.toString(),
+      accepted.toString()
+    );
+
+    // the unspent SOL comes back with the tokens
+    const claimed = await claimBatchOrder(program, launch, user2);
+    assert.strictEqual(claimed.tokens.toString(), tokenAmount.toString());
+    assert.strictEqual(claimed.refund, lamports.sub(accepted).toNumber());
+  });
+
+  it("Pause trading globally and per pool", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000)
+    );
+    const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LP_SEED_PREFIX),
+        launch.pool.toBuffer(),
+        user.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const liquidityAccounts = {
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      mintTokenOne: launch.mint,
+      poolTokenAccountOne: launch.poolTokenAccount,
+      userTokenAccountOne: launch.userTokenAccount,
+      liquidityProviderAccount,
+      user: user.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+    const buy = () =>
+      program.methods
+        .swap(new BN(200000000), { buy: {} }, new BN(0))
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          solVault: launch.solVault,
+          treasury: treasury.publicKey,
+          mintTokenOne: launch.mint,
+          poolTokenAccountOne: launch.poolTokenAccount,
+          userTokenAccountOne: launch.userTokenAccount,
+          user: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    const addLiquidity = () =>
+      program.methods
+        .addLiquidity(new BN(1000000), new BN(0))
+        .accounts(liquidityAccounts)
+        .signers([user])
+        .rpc();
+    const removeLiquidity = () =>
+      program.methods
+        .removeLiquidity(new BN(10))
+        .accounts(liquidityAccounts)
+        .signers([user])
+        .rpc();
+    const setPoolPause = (pause: any) =>
+      program.methods
+        .setPoolPause(pause)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: launch.pool,
+          mintTokenOne: launch.mint,
+          admin: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+    const setPause = (admin: Keypair, pause: any) =>
+      program.methods
+        .setPause(pause)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    const expectPaused = async (action: () => Promise<string>) => {
+      try {
+        await action();
+        assert.fail("the pause should stop this");
+      } catch (error) {
+        expect(String(error)).to.contain("Paused");
+      }
+    };
+
+    await buy();
+
+    // a paused pool stops trading and liquidity changes
+    await setPoolPause({ paused: {} });
+    await expectPaused(buy);
+    await expectPaused(addLiquidity);
+    await expectPaused(removeLiquidity);
+
+    // withdrawals-only still lets providers leave
+    await setPoolPause({ withdrawalsOnly: {} });
+    await expectPaused(buy);
+    await expectPaused(addLiquidity);
+    await removeLiquidity();
+
+    await setPoolPause({ unpaused: {} });
+    await buy();
+
+    // the global pause covers every pool and only the admin sets it
+    try {
+      await setPause(user2, { paused: {} });
+      assert.fail("only the admin should pause");
+    } catch (error) {
+      expect(String(error)).to.contain("ConstraintHasOne");
+    }
+    await setPause(user, { paused: {} });
+    await expectPaused(buy);
+    await setPause(user, { unpaused: {} });
+    await buy();
+
+    // launch deposits are held back as well, even in withdrawals-only mode
+    const presale = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000),
+      { raydium: {} },
+      {
+        presale: {
+          startSlot: new BN(0),
+          endSlot: new BN((await connection.getSlot()) + 1000),
+          softCap: new BN(1_000_000_000),
+          hardCap: new BN(10_000_000_000),
+          allowlistRoot: null,
+        },
+      }
+    );
+    const deposit = () =>
+      program.methods
+        .presaleDeposit(new BN(1_000_000), new BN(0), [])
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          pool: presale.pool,
+          solVault: presale.solVault,
+          presaleBid: getPresaleBid(
+            presale.pool,
+            user.publicKey,
+            program.programId
+          ),
+          mintTokenOne: presale.mint,
+          user: user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    await setPause(user, { withdrawalsOnly: {} });
+    await expectPaused(deposit);
+    await setPause(user, { unpaused: {} });
+    await deposit();
+  });
+
+  it("Govern the config with a 2-of-3 multisig", async () => {
+    const [curveConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from(curveSeed)],
+      program.programId
+    );
+    const signerTwo = Keypair.generate();
+    const signerThree = Keypair.generate();
+    const launch = await launchPool(
+      program,
+      user,
+      { constantProduct: {} },
+      CONSTANT_PRODUCT_PARAMS,
+      new BN(1000000000000000)
+    );
+    const createProposal = async (proposer: Keypair, action: any) => {
+      const { proposalCount } =
+        await program.account.curveConfiguration.fetch(curveConfig);
+      const [proposal] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(PROPOSAL_SEED_PREFIX),
+          proposalCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      await program.methods
+        .createProposal(action)
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          proposal,
+          proposer: proposer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+      return proposal;
+    };
+    const approveProposal = (proposal: PublicKey, signer: Keypair) =>
+      program.methods
+        .approveProposal()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          proposal,
+          signer: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+    const executeProposal = (
+      proposal: PublicKey,
+      remainingAccounts: PublicKey[] = [],
+      pendingUpdate: PublicKey = null
+    ) =>
+      program.methods
+        .executeProposal()
+        .accounts({
+          dexConfigurationAccount: curveConfig,
+          proposal,
+          pendingUpdate,
+          executor: user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          remainingAccounts.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([user])
+        .rpc();
+    const closeProposal = async (proposal: PublicKey) => {
+      const { proposer } = await program.account.proposal.fetch(proposal);
+      return program.methods
+        .closeProposal()
+        .accounts({ dexConfigurationAccount: curveConfig, proposal, proposer })
+        .rpc();
+    };
+    const expectError = async (action: () => Promise<any>, error: string) => {
+      try {
+        await action();
+        assert.fail(`expected ${error}`);
+      } catch (e) {
+        expect(String(e)).to.contain(error);
+      }
+    };
+
+    await program.methods
+      .setMultisig(
+        [user.publicKey, signerTwo.publicKey, signerThree.publicKey],
+        2
+      )
+      .accounts({ dexConfigurationAccount: curveConfig, admin: user.publicKey })
+      .signers([user])
+      .rpc();
+    let config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.admin.equals(PublicKey.default));
+    assert.strictEqual(config.multisigThreshold, 2);
+
+    // the former admin key no longer acts alone
+    await expectError(
+      () =>
+        program.methods
+          .setPause({ paused: {} })
+          .accounts({
+            dexConfigurationAccount: curveConfig,
+            admin: user.publicKey,
+          })
+          .signers([user])
+          .rpc(),
+      "ConstraintHasOne"
+    );
+    await expectError(
+      () => createProposal(user2, { setPause: { pause: { paused: {} } } }),
+      "NotMultisigSigner"
+    );
+
+    // one approval is not enough, and nobody approves twice
+    const pause = await createProposal(user, {
+      setPause: { pause: { paused: {} } },
+    });
+    await expectError(() => executeProposal(pause), "NotEnoughApprovals");
+    await expectError(() => approveProposal(pause, user), "AlreadyApproved");
+    await approveProposal(pause, signerTwo);
+    await executeProposal(pause);
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.deepEqual(config.pause, { paused: {} });
+    await expectError(() => executeProposal(pause), "ProposalAlreadyExecuted");
+
+    // fee changes and pool pauses go through proposals too,
+    // and fee changes still wait out the delay
+    const [pendingUpdate] = PublicKey.findProgramAddressSync(
+      [Buffer.from(PENDING_UPDATE_SEED_PREFIX)],
+      program.programId
+    );
+    const updateSellFee = async (sellFeeBps: number) => {
+      const update = await createProposal(user, {
+        updateConfig: {
+          update: {
+            buyFeeBps: 100,
+            sellFeeBps,
+            initialVirtualSolReserves: INITIAL_VIRTUAL_SOL_RESERVES,
+            initialVirtualTokenReserves: INITIAL_VIRTUAL_TOKEN_RESERVES,
+            completionThreshold: {
+              tokensSold: { amount: COMPLETION_TOKENS_SOLD },
+            },
+            treasury: treasury.publicKey,
+            raydiumAmmConfig: RAYDIUM_AMM_CONFIG,
+          },
+        },
+      });
+      await approveProposal(update, signerThree);
+      await expectError(
+        () => executeProposal(update),
+        "InvalidProposalAccounts"
+      );
+      await executeProposal(update, [], pendingUpdate);
+    };
+
+    await updateSellFee(100);
+    const { effectiveAt } =
+      await program.account.pendingUpdate.fetch(pendingUpdate);
+    await waitForTimestamp(effectiveAt);
+    await program.methods
+      .executeConfigUpdate()
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        pendingUpdate,
+        payer: user.publicKey,
+      })
+      .rpc();
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.strictEqual(config.sellFeeBps, 100);
+
+    await updateSellFee(200);
+    const cancel = await createProposal(user, { cancelConfigUpdate: {} });
+    await approveProposal(cancel, signerTwo);
+    await executeProposal(cancel, [pendingUpdate, user.publicKey]);
+    assert.isNull(await connection.getAccountInfo(pendingUpdate));
+
+    const poolPause = await createProposal(user, {
+      setPoolPause: { pool: launch.pool, pause: { withdrawalsOnly: {} } },
+    });
+    await approveProposal(poolPause, signerTwo);
+    await expectError(
+      () => executeProposal(poolPause, [user.publicKey]),
+      "InvalidProposalAccounts"
+    );
+    await executeProposal(poolPause, [launch.pool]);
+    const pool = await program.account.liquidityPool.fetch(launch.pool);
+    assert.deepEqual(pool.pause, { withdrawalsOnly: {} });
+
+    // executed proposals can be closed, open ones cannot
+    await closeProposal(pause);
+    assert.isNull(await connection.getAccountInfo(pause));
+    const stale = await createProposal(user, {
+      setPause: { pause: { unpaused: {} } },
+    });
+    await expectError(() => closeProposal(stale), "ProposalStillOpen");
+
+    // changing the signer set invalidates proposals still open
+    const rotate = await createProposal(user, {
+      setMultisig: {
+        signers: [user.publicKey, signerTwo.publicKey, signerThree.publicKey],
+        threshold: 2,
+      },
+    });
+    const { multisigEpoch } = config;
+    await approveProposal(rotate, signerTwo);
+    await executeProposal(rotate);
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.multisigEpoch.eq(multisigEpoch.addn(1)));
+    await expectError(() => approveProposal(stale, signerTwo), "ProposalStale");
+    await expectError(() => executeProposal(stale), "ProposalStale");
+    await closeProposal(stale);
+    assert.isNull(await connection.getAccountInfo(stale));
+
+    // hand control back to a single key for the remaining tests,
+    // which only takes over once it accepts
+    const restore = await createProposal(user, {
+      setAdmin: { admin: user.publicKey },
+    });
+    await approveProposal(restore, signerThree);
+    await executeProposal(restore);
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.admin.equals(PublicKey.default));
+    assert.isTrue(config.pendingAdmin.equals(user.publicKey));
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        dexConfigurationAccount: curveConfig,
+        newAdmin: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    config = await program.account.curveConfiguration.fetch(curveConfig);
+    assert.isTrue(config.admin.equals(user.publicKey));
+    assert.lengthOf(config.multisigSigners, 0);
+    assert.isTrue(config.multisigEpoch.eq(multisigEpoch.addn(2)));
+
+    await program.methods
+      .setPause({ unpaused: {} })
+      .accounts({ dexConfigurationAccount: curveConfig, admin: user.publicKey })
+      .signers([user])
+      .rpc();
+  });
+});
+
+function comparePublicKeys(pubkey1: PublicKey, pubkey2: PublicKey): number {
+  const key1Bytes = pubkey1.toBuffer();
+  const key2Bytes = pubkey2.toBuffer();
+
+  for (let i = 0; i < key1Bytes.length; i++) {
+    if (key1Bytes[i] > key2Bytes[i]) {
+      return 1;
+    } else if (key1Bytes[i] < key2Bytes[i]) {
+      return -1;
+    }
+  }
+  return 0;
+}
+
+function generateSeed(tokenOne: PublicKey, tokenTwo: PublicKey): string {
+  return comparePublicKeys(tokenOne, tokenTwo) > 0
+    ? `${tokenOne.toString()}${tokenTwo.toString()}`
+    : `${tokenTwo.toString()}${tokenOne.toString()}`;
+}
+
+function getSolVault(pool: PublicKey, programId: PublicKey): PublicKey {
+  const [solVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from(SOL_VAULT_SEED_PREFIX), pool.toBuffer()],
+    programId
+  );
+  return solVault;
+}
+
+// Reserves the curve prices against: [token, SOL], virtual + real
+function getEffectiveReserves(pool: {
+  reserveOne: BN;
+  reserveTwo: BN;
+  virtualSolReserves: BN;
+  virtualTokenReserves: BN;
+}): [BN, BN] {
+  return [
+    pool.reserveOne.add(pool.virtualTokenReserves),
+    pool.reserveTwo.add(pool.virtualSolReserves),
+  ];
+}
+
+// Total SOL to sell `supply` tokens along a linear curve, mirroring LinearCurve::cost
+function getLinearCost(basePrice: BN, slope: BN, supply: BN): BN {
+  const base = basePrice.mul(supply);
+  const sloped = slope.mul(supply).div(PRICE_SCALE).mul(supply).divn(2);
+  return base.add(sloped).div(PRICE_SCALE);
+}
+
+// Off-chain quote mirroring the on-chain integer math, rounded in the pool's favor
+function getAmountOut(amountIn: BN, reserveIn: BN, reserveOut: BN): BN {
+  return reserveOut.mul(amountIn).div(reserveIn.add(amountIn));
+}
+
+function getAmountIn(amountOut: BN, reserveIn: BN, reserveOut: BN): BN {
+  const numerator = reserveIn.mul(amountOut);
+  const denominator = reserveOut.sub(amountOut);
+  return numerator.add(denominator.subn(1)).div(denominator);
+}
+
+function getAmountBeforeFee(amount: BN, feeBps: BN): BN {
+  const denominator = new BN(10_000).sub(feeBps);
+  const gross = amount.muln(10_000);
+  return gross.add(denominator.subn(1)).div(denominator);
+}
+
+function getFee(amount: BN, feeBps: BN): BN {
+  const denominator = new BN(10_000);
+  const fee = amount.mul(feeBps);
+  return fee.add(denominator.subn(1)).div(denominator);
+}
+
+// Raydium CPMM accounts for a token/WSOL pool created by `creator`
+function getRaydiumAccounts(
+  mint: PublicKey,
+  creator: PublicKey,
+  ammConfig = RAYDIUM_AMM_CONFIG
+) {
+  const [token0Mint, token1Mint] =
+    Buffer.compare(mint.toBuffer(), NATIVE_MINT.toBuffer()) < 0
+      ? [mint, NATIVE_MINT]
+      : [NATIVE_MINT, mint];
+  const pda = (seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, RAYDIUM_CPMM_PROGRAM_ID)[0];
+
+  const raydiumPoolState = pda([
+    Buffer.from("pool"),
+    ammConfig.toBuffer(),
+    token0Mint.toBuffer(),
+    token1Mint.toBuffer(),
+  ]);
+  const lpMint = pda([
+    Buffer.from("pool_lp_mint"),
+    raydiumPoolState.toBuffer(),
+  ]);
+  const [payerLpToken] = PublicKey.findProgramAddressSync(
+    [creator.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), lpMint.toBuffer()],
+    ASSOCIATED_PROGRAM_ID
+  );
+
+  return {
+    payerLpToken,
+    ammConfig,
+    raydiumAuthority: pda([Buffer.from("vault_and_lp_mint_auth_seed")]),
+    raydiumPoolState,
+    lpMint,
+    token0Vault: pda([
+      Buffer.from("pool_vault"),
+      raydiumPoolState.toBuffer(),
+      token0Mint.toBuffer(),
+    ]),
+    token1Vault: pda([
+      Buffer.from("pool_vault"),
+      raydiumPoolState.toBuffer(),
+      token1Mint.toBuffer(),
+    ]),
+    createPoolFee: pda([Buffer.from("create_pool_fee")]),
+    observationState: pda([
+      Buffer.from("observation"),
+      raydiumPoolState.toBuffer(),
+    ]),
+    raydiumProgram: RAYDIUM_CPMM_PROGRAM_ID,
+  };
+}
+
+// Raydium CPMM fee tier at `index`
+function getRaydiumAmmConfig(index: number) {
+  const indexBytes = Buffer.alloc(2);
+  indexBytes.writeUInt16BE(index);
+
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("amm_config"), indexBytes],
+    RAYDIUM_CPMM_PROGRAM_ID
+  )[0];
+}
+
+// Meteora dynamic AMM accounts for a token/WSOL pool created by `creator`.
+// Vault, fee and metadata accounts only matter to the real program, so the mock
+// gets stand-in keys for them.
+function getMeteoraAccounts(mint: PublicKey, creator: PublicKey) {
+  const pda = (seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, METEORA_AMM_PROGRAM_ID)[0];
+  const [firstMint, secondMint] =
+    Buffer.compare(mint.toBuffer(), NATIVE_MINT.toBuffer()) > 0
+      ? [mint, NATIVE_MINT]
+      : [NATIVE_MINT, mint];
+
+  const meteoraPool = pda([firstMint.toBuffer(), secondMint.toBuffer()]);
+  const lpMint = pda([Buffer.from("lp_mint"), meteoraPool.toBuffer()]);
+  const [payerLpToken] = PublicKey.findProgramAddressSync(
+    [creator.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), lpMint.toBuffer()],
+    ASSOCIATED_PROGRAM_ID
+  );
+  const unused = () => Keypair.generate().publicKey;
+
+  return {
+    payerLpToken,
+    meteoraPool,
+    lpMint,
+    aVault: pda([
+      Buffer.from("vault"),
+      mint.toBuffer(),
+      meteoraPool.toBuffer(),
+    ]),
+    bVault: pda([
+      Buffer.from("vault"),
+      NATIVE_MINT.toBuffer(),
+      meteoraPool.toBuffer(),
+    ]),
+    aVaultLpMint: unused(),
+    bVaultLpMint: unused(),
+    aVaultLp: unused(),
+    bVaultLp: unused(),
+    protocolTokenAFee: unused(),
+    protocolTokenBFee: unused(),
+    feeOwner: unused(),
+    mintMetadata: unused(),
+    metadataProgram: unused(),
+    vaultProgram: unused(),
+    meteoraProgram: METEORA_AMM_PROGRAM_ID,
+  };
+}
+
+function getPresaleBid(
+  pool: PublicKey,
+  bidder: PublicKey,
+  programId: PublicKey
+): PublicKey {
+  const [presaleBid] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PRESALE_BID_SEED_PREFIX),
+      pool.toBuffer(),
+      bidder.toBuffer(),
+    ],
+    programId
+  );
+  return presaleBid;
+}
+
+// Allowlist merkle tree mirroring utils/merkle.rs: keccak(wallet || cap as
+// u64 LE) leaves, pairs hashed in sorted order, unpaired nodes move up as is
+function getBatchOrder(
+  pool: PublicKey,
+  buyer: PublicKey,
+  programId: PublicKey
+): PublicKey {
+  const [batchOrder] = PublicKey.findProgramAddressSync(
+    [Buffer.from(BATCH_ORDER_SEED_PREFIX), pool.toBuffer(), buyer.toBuffer()],
+    programId
+  );
+  return batchOrder;
+}
+
+function getAllowlistLeaf(wallet: PublicKey, maxAllocation: BN): Buffer {
+  return Buffer.from(
+    keccak_256(
+      Buffer.concat([
+        wallet.toBuffer(),
+        maxAllocation.toArrayLike(Buffer, "le", 8),
+      ])
+    )
+  );
+}
+
+function hashPair(first: Buffer, second: Buffer): Buffer {
+  const [low, high] =
+    Buffer.compare(first, second) <= 0 ? [first, second] : [second, first];
+  return Buffer.from(keccak_256(Buffer.concat([low, high])));
+}
+
+function buildMerkleTree(leaves: Buffer[]): Buffer[][] {
+  const layers = [leaves];
+  while (layers[layers.length - 1].length > 1) {
+    const layer = layers[layers.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < layer.length; i += 2) {
+      next.push(
+        i + 1 < layer.length ? hashPair(layer[i], layer[i + 1]) : layer[i]
+      );
+    }
+    layers.push(next);
+  }
+  return layers;
+}
+
+function getMerkleRoot(layers: Buffer[][]): Buffer {
+  return layers[layers.length - 1][0];
+}
+
+function getMerkleProof(layers: Buffer[][], index: number): Buffer[] {
+  const proof: Buffer[] = [];
+  for (const layer of layers.slice(0, -1)) {
+    const sibling = index ^ 1;
+    if (sibling < layer.length) {
+      proof.push(layer[sibling]);
+    }
+    index = Math.floor(index / 2);
+  }
+  return proof;
+}
+
+// Creates a fresh mint and a pool seeded with tokens only
+async function launchPool(
+  program: Program<Pump>,
+  payer: Keypair,
+  curveType: any,
+  curveParams: BN[],
+  tokenAmount: BN,
+  migrationTarget: any = { raydium: {} },
+  launchMode: any = { instant: {} }
+) {
+  const mint = await createMint(
+    connection,
+    payer,
+    payer.publicKey,
+    payer.publicKey,
+    6
+  );
+  const userTokenAccount = (
+    await getOrCreateAssociatedTokenAccount(
+      connection,
+      payer,
+      mint,
+      payer.publicKey
+    )
+  ).address;
+  await mintTo(
+    connection,
+    payer,
+    mint,
+    userTokenAccount,
+    payer.publicKey,
+    BigInt(tokenAmount.toString())
+  );
+
+  const [curveConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from(curveSeed)],
+    program.programId
+  );
+  const [pool] = PublicKey.findProgramAddressSync(
+    [Buffer.from(POOL_SEED_PREFIX), mint.toBuffer()],
+    program.programId
+  );
+  const [liquidityProviderAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from(LP_SEED_PREFIX), pool.toBuffer(), payer.publicKey.toBuffer()],
+    program.programId
+  );
+  const solVault = getSolVault(pool, program.programId);
+  const poolTokenAccount = await getAssociatedTokenAddress(mint, pool, true);
+
+  await program.methods
+    .createPool(curveType, curveParams, migrationTarget, launchMode)
+    .accounts({
+      dexConfigurationAccount: curveConfig,
+      pool,
+      solVault,
+      mintTokenOne: mint,
+      poolTokenAccountOne: poolTokenAccount,
+      payer: payer.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc();
+
+  await program.methods
+    .addLiquidity(tokenAmount, new BN(0))
+    .accounts({
+      dexConfigurationAccount: curveConfig,
+      pool,
+      solVault,
+      mintTokenOne: mint,
+      poolTokenAccountOne: poolTokenAccount,
+      userTokenAccountOne: userTokenAccount,
+      liquidityProviderAccount,
+      user: payer.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc();
+
+  return { mint, pool, solVault, poolTokenAccount, userTokenAccount };
+}
+
+// Buys and sells back on a supply curve with a raised compute unit limit
+async function tradeAlongCurve(
+  program: Program<Pump>,
+  payer: Keypair,
+  curveType: any,
+  curveParams: BN[]
+) {
+  const [curveConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from(curveSeed)],
+    program.programId
+  );
+  const launch = await launchPool(
+    program,
+    payer,
+    curveType,
+    curveParams,
+    new BN(1000000000000000)
+  );
+  const config = await program.account.curveConfiguration.fetch(curveConfig);
+  const accounts = {
+    pool: launch.pool,
+    solVault: launch.solVault,
+    treasury: config.treasury,
+    mintTokenOne: launch.mint,
+    poolTokenAccountOne: launch.poolTokenAccount,
+    userTokenAccountOne: launch.userTokenAccount,
+    dexConfigurationAccount: curveConfig,
+    user: payer.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+    rent: SYSVAR_RENT_PUBKEY,
+    systemProgram: SystemProgram.programId,
+  };
+  const computeUnits = ComputeBudgetProgram.setComputeUnitLimit({
+    units: 1_400_000,
+  });
+
+  const amountIn = new BN(10_000_000);
+  await program.methods
+    .swap(amountIn, { buy: {} }, new BN(1))
+    .accounts(accounts)
+    .preInstructions([computeUnits])
+    .signers([payer])
+    .rpc();
+
+  const bought = await program.account.liquidityPool.fetch(launch.pool);
+  assert.isTrue(bought.tokensSold.gtn(0));
+  // the fee goes to the treasury, the rest into the reserves
+  assert.strictEqual(
+    bought.reserveTwo.toString(),
+    amountIn.sub(getFee(amountIn, new BN(config.buyFeeBps))).toString()
+  );
+
+  // selling half of the tokens back walks down the same curve
+  const amountSold = bought.tokensSold.divn(2);
+  await program.methods
+    .swap(amountSold, { sell: {} }, new BN(1))
+    .accounts(accounts)
+    .preInstructions([computeUnits])
+    .signers([payer])
+    .rpc();
+
+  const sold = await program.account.liquidityPool.fetch(launch.pool);
+  assert.isTrue(sold.tokensSold.lt(bought.tokensSold));
+  assert.isTrue(sold.reserveTwo.gtn(0));
+  assert.isTrue(sold.reserveTwo.lt(bought.reserveTwo));
+}
+
+// Claims a batch order, returning the tokens received and the SOL refunded
+async function claimBatchOrder(
+  program: Program<Pump>,
+  launch: {
+    mint: PublicKey;
+    pool: PublicKey;
+    solVault: PublicKey;
+    poolTokenAccount: PublicKey;
+  },
+  buyer: Keypair
+) {
+  const [curveConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from(curveSeed)],
+    program.programId
+  );
+  const buyerTokenAccount = (
+    await getOrCreateAssociatedTokenAccount(
+      connection,
+      buyer,
+      launch.mint,
+      buyer.publicKey
+    )
+  ).address;
+  const balanceBefore = (await getAccount(connection, buyerTokenAccount))
+    .amount;
+  const vaultBefore = await connection.getBalance(launch.solVault);
+  await program.methods
+    .claimOrder()
+    .accounts({
+      dexConfigurationAccount: curveConfig,
+      pool: launch.pool,
+      solVault: launch.solVault,
+      batchOrder: getBatchOrder(
+        launch.pool,
+        buyer.publicKey,
+        program.programId
+      ),
+      mintTokenOne: launch.mint,
+      poolTokenAccountOne: launch.poolTokenAccount,
+      userTokenAccountOne: buyerTokenAccount,
+      user: buyer.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([buyer])
+    .rpc();
+  const balanceAfter = (await getAccount(connection, buyerTokenAccount)).amount;
+
+  return {
+    tokens: new BN((balanceAfter - balanceBefore).toString()),
+    refund: vaultBefore - (await connection.getBalance(launch.solVault)),
+  };
+}
+
+async function waitForSlot(slot: number) {
+  while ((await connection.getSlot()) < slot) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+}
+
+// The validator clock, not wall time, decides when a queued update applies
+async function waitForTimestamp(timestamp: BN) {
+  // unix_timestamp follows four other 8 byte fields in the clock sysvar
+  const clockTime = async () => {
+    const clock = await connection.getAccountInfo(SYSVAR_CLOCK_PUBKEY);
+    return new BN(clock.data.subarray(32, 40), "le");
+  };
+  while ((await clockTime()).lt(timestamp)) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+}
+
+async function airdrop(publicKey: PublicKey, amount: number) {
+  // 1 - Request Airdrop
+  const signature = await connection.requestAirdrop(publicKey, amount);
+  // 2 - Fetch the latest blockhash
+  const { blockhash, lastValidBlockHeight } =
+    await connection.getLatestBlockhash();
+  // 3 - Confirm transaction success
+  await connection.confirmTransaction({
+    blockhash,
+    lastValidBlockHeight,
+    signature,
+  });
+}
+